@@ -0,0 +1,124 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use crate::{Cell, Maze, Point, SearchStatus, Solution};
+use super::{distance_estimate, SearchBudget};
+
+#[derive(Debug, Clone, Copy)]
+pub struct AntConfig {
+    pub ants: usize,
+    pub iterations: usize,
+    pub evaporation: f64,
+    pub alpha: f64,
+    pub beta: f64,
+    pub seed: u64,
+}
+
+impl Default for AntConfig {
+    fn default() -> Self {
+        AntConfig { ants: 20, iterations: 50, evaporation: 0.1, alpha: 1.0, beta: 2.0, seed: 42 }
+    }
+}
+
+fn walk_ant(maze: &Maze, pheromone: &HashMap<Point, f64>, config: &AntConfig, step_limit: usize, rng: &mut StdRng) -> (Option<Vec<Point>>, usize) {
+    let mut stack = vec![maze.start];
+    let mut visited = HashSet::new();
+    visited.insert(maze.start);
+    let mut steps = 0;
+    
+    while let Some(&current) = stack.last() {
+        if current == maze.end {
+            return (Some(stack), steps);
+        }
+        if steps >= step_limit {
+            break;
+        }
+        steps += 1;
+        
+        let candidates: Vec<Point> = maze.get_neighbors(current).into_iter()
+            .filter(|p| !visited.contains(p))
+            .collect();
+        let next = candidates.choose_weighted(rng, |p| {
+            let desirability = 1.0 / (1.0 + distance_estimate(maze, *p) as f64);
+            pheromone[p].powf(config.alpha) * desirability.powf(config.beta)
+        });
+        match next {
+            Ok(&next) => {
+                visited.insert(next);
+                stack.push(next);
+            }
+            Err(_) => {
+                stack.pop();
+            }
+        }
+    }
+    
+    (None, steps)
+}
+
+pub fn ant_colony_solve(maze: &Maze, budget: &SearchBudget, config: &AntConfig) -> (Solution, Vec<f64>) {
+    let start_time = Instant::now();
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut pheromone: HashMap<Point, f64> = (0..maze.height)
+        .flat_map(|y| (0..maze.width).map(move |x| Point { x, y }))
+        .filter(|p| maze.grid[p.y][p.x] != Cell::Wall)
+        .map(|p| (p, 1.0))
+        .collect();
+    let step_limit = 4 * pheromone.len();
+    let mut best: Option<Vec<Point>> = None;
+    let mut nodes_explored = 0;
+    let mut curve = Vec::with_capacity(config.iterations);
+    let mut status = None;
+    
+    'iterations: for _ in 0..config.iterations {
+        let mut paths = Vec::new();
+        for _ in 0..config.ants {
+            if let Some(stop) = budget.check(nodes_explored, start_time) {
+                status = Some(stop);
+                break 'iterations;
+            }
+            let (path, steps) = walk_ant(maze, &pheromone, config, step_limit, &mut rng);
+            nodes_explored += steps;
+            paths.extend(path);
+        }
+        
+        let iteration_best = paths.iter().map(|p| p.len() - 1).min();
+        curve.push(iteration_best.map_or(f64::NAN, |length| length as f64));
+        
+        for level in pheromone.values_mut() {
+            *level *= 1.0 - config.evaporation;
+        }
+        for path in &paths {
+            if best.as_ref().is_none_or(|b| path.len() < b.len()) {
+                best = Some(path.clone());
+            }
+            let deposit = 1.0 / path.len() as f64;
+            for cell in path {
+                *pheromone.get_mut(cell).unwrap() += deposit;
+            }
+        }
+        if let Some(best) = &best {
+            let deposit = 1.0 / best.len() as f64;
+            for cell in best {
+                *pheromone.get_mut(cell).unwrap() += deposit;
+            }
+        }
+    }
+    
+    let status = status.unwrap_or(if best.is_some() {
+        SearchStatus::Solved
+    } else {
+        SearchStatus::NoPath
+    });
+    
+    let solution = Solution::new(
+        "Ant Colony".to_string(),
+        best.unwrap_or_default(),
+        nodes_explored,
+        start_time.elapsed().as_micros(),
+        status,
+    );
+    (solution, curve)
+}
@@ -1,45 +1,47 @@
 use std::collections::HashMap;
 use std::time::Instant;
-use crate::{Maze, Point, Solution};
+use crate::{Maze, Point, SearchStatus, Solution};
+use super::{distance_estimate, reconstruct_path, SearchBudget};
 
-pub fn astar_solve(maze: &Maze) -> Solution {
+pub fn astar_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
     let start_time = Instant::now();
     let mut open_set = Vec::new();
     let mut came_from = HashMap::new();
     let mut g_score: HashMap<Point, usize> = HashMap::new();
     let mut f_score: HashMap<Point, usize> = HashMap::new();
     let mut nodes_explored = 0;
+    let mut last = maze.start;
     
-    let heuristic = |p: Point| -> usize {
-        ((p.x as i32 - maze.end.x as i32).abs() + (p.y as i32 - maze.end.y as i32).abs()) as usize
-    };
+    let heuristic = |p: Point| distance_estimate(maze, p);
     
     g_score.insert(maze.start, 0);
     f_score.insert(maze.start, heuristic(maze.start));
     open_set.push((f_score[&maze.start], maze.start));
     
     while !open_set.is_empty() {
-        open_set.sort_by(|a, b| b.0.cmp(&a.0));
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return Solution::new(
+                "A*".to_string(),
+                reconstruct_path(&came_from, maze.start, last),
+                nodes_explored,
+                start_time.elapsed().as_micros(),
+                status,
+            ).with_bound(Some(1.0));
+        }
+        
+        open_set.sort_by_key(|&(f, _)| std::cmp::Reverse(f));
         let (_, current) = open_set.pop().unwrap();
         nodes_explored += 1;
+        last = current;
         
         if current == maze.end {
-            let mut path = Vec::new();
-            let mut node = current;
-            while node != maze.start {
-                path.push(node);
-                node = came_from[&node];
-            }
-            path.push(maze.start);
-            path.reverse();
-            
-            return Solution {
-                algorithm: "A*".to_string(),
-                path,
-                path_length: 0,
+            return Solution::new(
+                "A*".to_string(),
+                reconstruct_path(&came_from, maze.start, current),
                 nodes_explored,
-                time_taken: start_time.elapsed().as_micros(),
-            };
+                start_time.elapsed().as_micros(),
+                SearchStatus::Solved,
+            ).with_bound(Some(1.0));
         }
         
         for neighbor in maze.get_neighbors(current) {
@@ -51,19 +53,20 @@ pub fn astar_solve(maze: &Maze) -> Solution {
                 let f = tentative_g + heuristic(neighbor);
                 f_score.insert(neighbor, f);
                 
-                if !open_set.iter().any(|(_, p)| *p == neighbor) {
-                    open_set.push((f, neighbor));
+                match open_set.iter_mut().find(|(_, p)| *p == neighbor) {
+                    Some(entry) => entry.0 = f,
+                    None => open_set.push((f, neighbor)),
                 }
             }
         }
     }
     
-    Solution {
-        algorithm: "A*".to_string(),
-        path: Vec::new(),
-        path_length: 0,
+    Solution::new(
+        "A*".to_string(),
+        Vec::new(),
         nodes_explored,
-        time_taken: start_time.elapsed().as_micros(),
-    }
+        start_time.elapsed().as_micros(),
+        SearchStatus::NoPath,
+    ).with_bound(Some(1.0))
 }
 
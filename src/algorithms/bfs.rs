@@ -1,37 +1,41 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::time::Instant;
-use crate::{Maze, Solution};
+use crate::{Maze, SearchStatus, Solution};
+use super::{reconstruct_path, SearchBudget};
 
-pub fn bfs_solve(maze: &Maze) -> Solution {
+pub fn bfs_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
     let start_time = Instant::now();
     let mut queue = VecDeque::new();
     let mut visited = HashSet::new();
     let mut parent = HashMap::new();
     let mut nodes_explored = 0;
+    let mut last = maze.start;
     
     queue.push_back(maze.start);
     visited.insert(maze.start);
     
     while let Some(current) = queue.pop_front() {
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return Solution::new(
+                "BFS".to_string(),
+                reconstruct_path(&parent, maze.start, last),
+                nodes_explored,
+                start_time.elapsed().as_micros(),
+                status,
+            ).with_bound(Some(1.0));
+        }
+        
         nodes_explored += 1;
+        last = current;
         
         if current == maze.end {
-            let mut path = Vec::new();
-            let mut node = current;
-            while node != maze.start {
-                path.push(node);
-                node = parent[&node];
-            }
-            path.push(maze.start);
-            path.reverse();
-            
-            return Solution {
-                algorithm: "BFS".to_string(),
-                path,
-                path_length: 0,
+            return Solution::new(
+                "BFS".to_string(),
+                reconstruct_path(&parent, maze.start, current),
                 nodes_explored,
-                time_taken: start_time.elapsed().as_micros(),
-            };
+                start_time.elapsed().as_micros(),
+                SearchStatus::Solved,
+            ).with_bound(Some(1.0));
         }
         
         for neighbor in maze.get_neighbors(current) {
@@ -43,12 +47,11 @@ pub fn bfs_solve(maze: &Maze) -> Solution {
         }
     }
     
-    Solution {
-        algorithm: "BFS".to_string(),
-        path: Vec::new(),
-        path_length: 0,
+    Solution::new(
+        "BFS".to_string(),
+        Vec::new(),
         nodes_explored,
-        time_taken: start_time.elapsed().as_micros(),
-    }
+        start_time.elapsed().as_micros(),
+        SearchStatus::NoPath,
+    ).with_bound(Some(1.0))
 }
-
@@ -0,0 +1,48 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use crate::SearchStatus;
+
+#[derive(Debug, Clone)]
+pub struct SearchBudget {
+    pub max_expansions: Option<usize>,
+    pub max_time: Option<Duration>,
+    pub cancel: Arc<AtomicBool>,
+}
+
+impl SearchBudget {
+    pub fn unlimited() -> Self {
+        SearchBudget {
+            max_expansions: None,
+            max_time: None,
+            cancel: Arc::new(AtomicBool::new(false)),
+        }
+    }
+    
+    pub fn capped(&self, max_expansions: usize) -> Self {
+        SearchBudget {
+            max_expansions: Some(self.max_expansions.unwrap_or(max_expansions)),
+            ..self.clone()
+        }
+    }
+    
+    pub fn check(&self, expansions: usize, start_time: Instant) -> Option<SearchStatus> {
+        if self.cancel.load(Ordering::Relaxed) {
+            return Some(SearchStatus::Cancelled);
+        }
+        
+        if let Some(max) = self.max_expansions {
+            if expansions >= max {
+                return Some(SearchStatus::ExpansionLimit);
+            }
+        }
+        
+        if let Some(max) = self.max_time {
+            if start_time.elapsed() >= max {
+                return Some(SearchStatus::TimedOut);
+            }
+        }
+        
+        None
+    }
+}
@@ -1,9 +1,11 @@
 use std::collections::HashSet;
 use std::time::Instant;
-use crate::{Maze, Point, Solution};
+use crate::{Maze, Point, SearchStatus, Solution};
+use super::SearchBudget;
 
-pub fn custom_solve(maze: &Maze) -> Solution {
+pub fn custom_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
     let start_time = Instant::now();
+    let budget = budget.capped(4 * maze.width * maze.height);
     let mut path = vec![maze.start];
     let mut visited = HashSet::new();
     visited.insert(maze.start);
@@ -12,10 +14,15 @@ pub fn custom_solve(maze: &Maze) -> Solution {
     let mut direction = 1;
     let mut current = maze.start;
     
-    let max_iterations = maze.width * maze.height * 4;
     let mut iterations = 0;
+    let mut status = SearchStatus::Solved;
     
-    while current != maze.end && iterations < max_iterations {
+    while current != maze.end {
+        if let Some(stop) = budget.check(iterations, start_time) {
+            status = stop;
+            break;
+        }
+        
         iterations += 1;
         
         let right_dir = (direction + 1) % 4;
@@ -24,11 +31,11 @@ pub fn custom_solve(maze: &Maze) -> Solution {
         
         let right_neighbor = get_neighbor_in_direction(current, right_dir);
         let has_right_wall = right_neighbor.is_none() || 
-            !is_valid_path(maze, right_neighbor.unwrap());
+            !is_valid_path(maze, current, right_neighbor.unwrap());
         
         let front_neighbor = get_neighbor_in_direction(current, front_dir);
         let can_go_front = front_neighbor.is_some() && 
-            is_valid_path(maze, front_neighbor.unwrap());
+            is_valid_path(maze, current, front_neighbor.unwrap());
         
         if !has_right_wall {
             direction = right_dir;
@@ -50,16 +57,10 @@ pub fn custom_solve(maze: &Maze) -> Solution {
         }
     }
     
-    Solution {
-        algorithm: "Wall-Following".to_string(),
-        path,
-        path_length: 0,
-        nodes_explored,
-        time_taken: start_time.elapsed().as_micros(),
-    }
+    Solution::new("Wall-Following".to_string(), path, nodes_explored, start_time.elapsed().as_micros(), status)
 }
 
-fn get_neighbor_in_direction(point: Point, direction: usize) -> Option<Point> {
+pub fn get_neighbor_in_direction(point: Point, direction: usize) -> Option<Point> {
     match direction {
         0 => Some(Point { x: point.x, y: point.y.wrapping_sub(1) }),
         1 => Some(Point { x: point.x + 1, y: point.y }),
@@ -69,9 +70,9 @@ fn get_neighbor_in_direction(point: Point, direction: usize) -> Option<Point> {
     }
 }
 
-fn is_valid_path(maze: &Maze, point: Point) -> bool {
+pub fn is_valid_path(maze: &Maze, from: Point, point: Point) -> bool {
     if point.x >= maze.width || point.y >= maze.height {
         return false;
     }
-    maze.grid[point.y][point.x] == crate::Cell::Path
+    maze.get_neighbors(from).contains(&point)
 }
@@ -1,37 +1,41 @@
 use std::collections::{HashMap, HashSet};
 use std::time::Instant;
-use crate::{Maze, Solution};
+use crate::{Maze, SearchStatus, Solution};
+use super::{reconstruct_path, SearchBudget};
 
-pub fn dfs_solve(maze: &Maze) -> Solution {
+pub fn dfs_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
     let start_time = Instant::now();
     let mut stack = Vec::new();
     let mut visited = HashSet::new();
     let mut parent = HashMap::new();
     let mut nodes_explored = 0;
+    let mut last = maze.start;
     
     stack.push(maze.start);
     visited.insert(maze.start);
     
     while let Some(current) = stack.pop() {
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return Solution::new(
+                "DFS".to_string(),
+                reconstruct_path(&parent, maze.start, last),
+                nodes_explored,
+                start_time.elapsed().as_micros(),
+                status,
+            );
+        }
+        
         nodes_explored += 1;
+        last = current;
         
         if current == maze.end {
-            let mut path = Vec::new();
-            let mut node = current;
-            while node != maze.start {
-                path.push(node);
-                node = parent[&node];
-            }
-            path.push(maze.start);
-            path.reverse();
-            
-            return Solution {
-                algorithm: "DFS".to_string(),
-                path,
-                path_length: 0,
+            return Solution::new(
+                "DFS".to_string(),
+                reconstruct_path(&parent, maze.start, current),
                 nodes_explored,
-                time_taken: start_time.elapsed().as_micros(),
-            };
+                start_time.elapsed().as_micros(),
+                SearchStatus::Solved,
+            );
         }
         
         for neighbor in maze.get_neighbors(current) {
@@ -43,12 +47,5 @@ pub fn dfs_solve(maze: &Maze) -> Solution {
         }
     }
     
-    Solution {
-        algorithm: "DFS".to_string(),
-        path: Vec::new(),
-        path_length: 0,
-        nodes_explored,
-        time_taken: start_time.elapsed().as_micros(),
-    }
+    Solution::new("DFS".to_string(), Vec::new(), nodes_explored, start_time.elapsed().as_micros(), SearchStatus::NoPath)
 }
-
@@ -1,17 +1,19 @@
 use std::collections::{HashMap, HashSet};
 use std::time::Instant;
-use crate::{Maze, Point, Solution};
+use crate::{Maze, Point, SearchStatus, Solution};
+use super::{reconstruct_path, SearchBudget};
 
-pub fn dijkstra_solve(maze: &Maze) -> Solution {
+pub fn dijkstra_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
     let start_time = Instant::now();
     let mut dist: HashMap<Point, usize> = HashMap::new();
     let mut prev: HashMap<Point, Point> = HashMap::new();
     let mut unvisited: HashSet<Point> = HashSet::new();
     let mut nodes_explored = 0;
+    let mut last = maze.start;
     
     for y in 0..maze.height {
         for x in 0..maze.width {
-            if maze.grid[y][x] == crate::Cell::Path {
+            if maze.grid[y][x] != crate::Cell::Wall {
                 let point = Point { x, y };
                 dist.insert(point, usize::MAX);
                 unvisited.insert(point);
@@ -22,6 +24,16 @@ pub fn dijkstra_solve(maze: &Maze) -> Solution {
     dist.insert(maze.start, 0);
     
     while !unvisited.is_empty() {
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return Solution::new(
+                "Dijkstra".to_string(),
+                reconstruct_path(&prev, maze.start, last),
+                nodes_explored,
+                start_time.elapsed().as_micros(),
+                status,
+            ).with_bound(Some(1.0));
+        }
+        
         let current = *unvisited.iter()
             .min_by_key(|p| dist.get(p).unwrap_or(&usize::MAX))
             .unwrap();
@@ -32,24 +44,16 @@ pub fn dijkstra_solve(maze: &Maze) -> Solution {
         
         unvisited.remove(&current);
         nodes_explored += 1;
+        last = current;
         
         if current == maze.end {
-            let mut path = Vec::new();
-            let mut node = current;
-            while node != maze.start {
-                path.push(node);
-                node = prev[&node];
-            }
-            path.push(maze.start);
-            path.reverse();
-            
-            return Solution {
-                algorithm: "Dijkstra".to_string(),
-                path,
-                path_length: 0,
+            return Solution::new(
+                "Dijkstra".to_string(),
+                reconstruct_path(&prev, maze.start, current),
                 nodes_explored,
-                time_taken: start_time.elapsed().as_micros(),
-            };
+                start_time.elapsed().as_micros(),
+                SearchStatus::Solved,
+            ).with_bound(Some(1.0));
         }
         
         for neighbor in maze.get_neighbors(current) {
@@ -63,12 +67,12 @@ pub fn dijkstra_solve(maze: &Maze) -> Solution {
         }
     }
     
-    Solution {
-        algorithm: "Dijkstra".to_string(),
-        path: Vec::new(),
-        path_length: 0,
+    Solution::new(
+        "Dijkstra".to_string(),
+        Vec::new(),
         nodes_explored,
-        time_taken: start_time.elapsed().as_micros(),
-    }
+        start_time.elapsed().as_micros(),
+        SearchStatus::NoPath,
+    ).with_bound(Some(1.0))
 }
 
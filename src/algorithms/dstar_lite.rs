@@ -0,0 +1,198 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::time::Instant;
+use crate::{Cell, Maze, Point, SearchStatus, Solution};
+use super::{teleport_distance, SearchBudget};
+
+const INFINITY: usize = usize::MAX;
+
+type Key = (usize, usize);
+
+pub struct DStarLite {
+    width: usize,
+    start: Point,
+    last: Point,
+    goal: Point,
+    km: usize,
+    g: Vec<usize>,
+    rhs: Vec<usize>,
+    queue: BinaryHeap<Reverse<(Key, usize)>>,
+    queued: Vec<Option<Key>>,
+    pub nodes_expanded: usize,
+    pub touched: HashSet<Point>,
+}
+
+impl DStarLite {
+    pub fn new(maze: &Maze) -> Self {
+        let cell_count = maze.width * maze.height;
+        let mut planner = DStarLite {
+            width: maze.width,
+            start: maze.start,
+            last: maze.start,
+            goal: maze.end,
+            km: 0,
+            g: vec![INFINITY; cell_count],
+            rhs: vec![INFINITY; cell_count],
+            queue: BinaryHeap::new(),
+            queued: vec![None; cell_count],
+            nodes_expanded: 0,
+            touched: HashSet::new(),
+        };
+        
+        let goal = planner.index(maze.end);
+        if maze.grid[maze.end.y][maze.end.x] != Cell::Wall {
+            planner.rhs[goal] = 0;
+            let key = planner.calculate_key(maze, maze.end);
+            planner.push(goal, key);
+        }
+        planner
+    }
+    
+    fn index(&self, p: Point) -> usize {
+        p.y * self.width + p.x
+    }
+    
+    fn point(&self, index: usize) -> Point {
+        Point { x: index % self.width, y: index / self.width }
+    }
+    
+    fn calculate_key(&self, maze: &Maze, p: Point) -> Key {
+        let i = self.index(p);
+        let best = self.g[i].min(self.rhs[i]);
+        (best.saturating_add(teleport_distance(maze, self.start, p)).saturating_add(self.km), best)
+    }
+    
+    fn push(&mut self, index: usize, key: Key) {
+        self.queued[index] = Some(key);
+        self.queue.push(Reverse((key, index)));
+    }
+    
+    fn top_key(&mut self) -> Key {
+        while let Some(&Reverse((key, index))) = self.queue.peek() {
+            if self.queued[index] == Some(key) {
+                return key;
+            }
+            self.queue.pop();
+        }
+        (INFINITY, INFINITY)
+    }
+    
+    fn update_vertex(&mut self, maze: &Maze, p: Point) {
+        let i = self.index(p);
+        self.touched.insert(p);
+        if p != self.goal {
+            self.rhs[i] = if maze.grid[p.y][p.x] != Cell::Wall {
+                maze.get_neighbors(p)
+                    .into_iter()
+                    .map(|n| self.g[self.index(n)].saturating_add(1))
+                    .min()
+                    .unwrap_or(INFINITY)
+            } else {
+                INFINITY
+            };
+        }
+        
+        self.queued[i] = None;
+        if self.g[i] != self.rhs[i] {
+            let key = self.calculate_key(maze, p);
+            self.push(i, key);
+        }
+    }
+    
+    pub fn compute_shortest_path(&mut self, maze: &Maze, budget: &SearchBudget) -> Option<SearchStatus> {
+        let start_time = Instant::now();
+        let mut expanded = 0;
+        
+        loop {
+            let top = self.top_key();
+            let s = self.index(self.start);
+            if top >= self.calculate_key(maze, self.start) && self.rhs[s] == self.g[s] {
+                return None;
+            }
+            
+            if let Some(status) = budget.check(expanded, start_time) {
+                return Some(status);
+            }
+            
+            let Reverse((old_key, i)) = self.queue.pop().unwrap();
+            let u = self.point(i);
+            self.queued[i] = None;
+            expanded += 1;
+            self.nodes_expanded += 1;
+            
+            let new_key = self.calculate_key(maze, u);
+            if old_key < new_key {
+                self.push(i, new_key);
+            } else if self.g[i] > self.rhs[i] {
+                self.g[i] = self.rhs[i];
+                for p in maze.get_predecessors(u) {
+                    self.update_vertex(maze, p);
+                }
+            } else {
+                self.g[i] = INFINITY;
+                self.update_vertex(maze, u);
+                for p in maze.get_predecessors(u) {
+                    self.update_vertex(maze, p);
+                }
+            }
+        }
+    }
+    
+    pub fn move_to(&mut self, p: Point) {
+        self.start = p;
+    }
+    
+    pub fn cell_changed(&mut self, maze: &Maze, p: Point) {
+        self.km += teleport_distance(maze, self.last, self.start);
+        self.last = self.start;
+        
+        self.update_vertex(maze, p);
+        let x = p.x as i32;
+        let y = p.y as i32;
+        for (nx, ny) in [(x, y + 1), (x + 1, y), (x, y - 1), (x - 1, y)] {
+            if nx >= 0 && nx < maze.width as i32 && ny >= 0 && ny < maze.height as i32 {
+                self.update_vertex(maze, Point { x: nx as usize, y: ny as usize });
+            }
+        }
+        if let Some(&partner) = maze.teleports.get(&p) {
+            self.update_vertex(maze, partner);
+        }
+    }
+    
+    pub fn path(&self, maze: &Maze) -> Vec<Point> {
+        if self.g[self.index(self.start)] == INFINITY {
+            return Vec::new();
+        }
+        
+        let mut path = vec![self.start];
+        let mut current = self.start;
+        while current != self.goal && path.len() <= maze.width * maze.height {
+            let next = maze.get_neighbors(current)
+                .into_iter()
+                .min_by_key(|n| self.g[self.index(*n)]);
+            match next {
+                Some(n) if self.g[self.index(n)] != INFINITY => {
+                    path.push(n);
+                    current = n;
+                }
+                _ => return Vec::new(),
+            }
+        }
+        path
+    }
+}
+
+pub fn dstar_lite_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
+    let start_time = Instant::now();
+    let mut planner = DStarLite::new(maze);
+    let stop = planner.compute_shortest_path(maze, budget);
+    let path = planner.path(maze);
+    
+    let status = match stop {
+        Some(status) => status,
+        None if path.is_empty() => SearchStatus::NoPath,
+        None => SearchStatus::Solved,
+    };
+    
+    Solution::new("D* Lite".to_string(), path, planner.nodes_expanded, start_time.elapsed().as_micros(), status).with_bound(Some(1.0))
+}
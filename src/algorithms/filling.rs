@@ -0,0 +1,155 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Instant;
+use crate::{Cell, Maze, Point, SearchStatus, Solution};
+use super::{reconstruct_path, SearchBudget};
+
+fn open_cells(maze: &Maze) -> HashSet<Point> {
+    let mut open = HashSet::new();
+    for y in 0..maze.height {
+        for x in 0..maze.width {
+            if maze.grid[y][x] != Cell::Wall {
+                open.insert(Point { x, y });
+            }
+        }
+    }
+    open
+}
+
+fn route_through(maze: &Maze, open: &HashSet<Point>) -> Option<Vec<Point>> {
+    let mut parent = HashMap::new();
+    let mut queue = VecDeque::new();
+    
+    if open.contains(&maze.start) {
+        parent.insert(maze.start, maze.start);
+        queue.push_back(maze.start);
+    }
+    
+    while let Some(current) = queue.pop_front() {
+        if current == maze.end {
+            return Some(reconstruct_path(&parent, maze.start, maze.end));
+        }
+        for neighbor in maze.get_neighbors(current) {
+            if open.contains(&neighbor) && !parent.contains_key(&neighbor) {
+                parent.insert(neighbor, current);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    
+    None
+}
+
+fn filled_solution(algorithm: &str, maze: &Maze, open: &HashSet<Point>, nodes_explored: usize, start_time: Instant) -> Solution {
+    let (path, status) = match route_through(maze, open) {
+        Some(path) => (path, SearchStatus::Solved),
+        None => (Vec::new(), SearchStatus::NoPath),
+    };
+    let mut cells: Vec<Point> = open.iter().copied().collect();
+    cells.sort_by_key(|p| (p.y, p.x));
+    Solution::new(algorithm.to_string(), path, nodes_explored, start_time.elapsed().as_micros(), status)
+        .with_cells(cells)
+}
+
+pub fn dead_end_fill_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
+    let start_time = Instant::now();
+    let mut open = open_cells(maze);
+    let mut queue: VecDeque<Point> = open.iter().copied().collect();
+    let mut nodes_explored = 0;
+    
+    while let Some(current) = queue.pop_front() {
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return Solution::new("Dead-End Fill".to_string(), Vec::new(), nodes_explored, start_time.elapsed().as_micros(), status);
+        }
+        
+        nodes_explored += 1;
+        
+        if current == maze.start || current == maze.end || !open.contains(&current) {
+            continue;
+        }
+        
+        let open_neighbors: Vec<Point> = maze.get_adjacent(current)
+            .into_iter()
+            .filter(|p| open.contains(p))
+            .collect();
+        
+        if open_neighbors.len() <= 1 {
+            open.remove(&current);
+            queue.extend(open_neighbors);
+        }
+    }
+    
+    filled_solution("Dead-End Fill", maze, &open, nodes_explored, start_time)
+}
+
+pub fn cul_de_sac_fill_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
+    let start_time = Instant::now();
+    let index = |p: Point| p.y * maze.width + p.x;
+    let cell_count = maze.width * maze.height;
+    
+    let mut disc = vec![usize::MAX; cell_count];
+    let mut low = vec![0; cell_count];
+    let mut parent: Vec<Option<Point>> = vec![None; cell_count];
+    let mut contains_end = vec![false; cell_count];
+    let mut blind_root = vec![false; cell_count];
+    let mut order = Vec::new();
+    let mut nodes_explored = 0;
+    
+    let mut stack: Vec<(Point, usize)> = Vec::new();
+    if maze.grid[maze.start.y][maze.start.x] != Cell::Wall {
+        disc[index(maze.start)] = 0;
+        contains_end[index(maze.start)] = maze.start == maze.end;
+        order.push(maze.start);
+        stack.push((maze.start, 0));
+        nodes_explored += 1;
+    }
+    
+    while let Some(&(current, next_index)) = stack.last() {
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return Solution::new("Cul-de-sac Fill".to_string(), Vec::new(), nodes_explored, start_time.elapsed().as_micros(), status);
+        }
+        
+        let neighbors = maze.get_adjacent(current);
+        let c = index(current);
+        
+        if next_index < neighbors.len() {
+            stack.last_mut().unwrap().1 += 1;
+            let neighbor = neighbors[next_index];
+            let n = index(neighbor);
+            
+            if disc[n] == usize::MAX {
+                disc[n] = order.len();
+                low[n] = order.len();
+                parent[n] = Some(current);
+                contains_end[n] = neighbor == maze.end;
+                order.push(neighbor);
+                stack.push((neighbor, 0));
+                nodes_explored += 1;
+            } else if parent[c] != Some(neighbor) {
+                low[c] = low[c].min(disc[n]);
+            }
+        } else {
+            stack.pop();
+            if let Some(above) = parent[c] {
+                let a = index(above);
+                low[a] = low[a].min(low[c]);
+                if contains_end[c] {
+                    contains_end[a] = true;
+                } else if low[c] >= disc[a] {
+                    blind_root[c] = true;
+                }
+            }
+        }
+    }
+    
+    let mut filled = vec![false; cell_count];
+    let mut open = HashSet::new();
+    for &cell in &order {
+        let c = index(cell);
+        filled[c] = blind_root[c] || parent[c].is_some_and(|p| filled[index(p)]);
+        if !filled[c] {
+            open.insert(cell);
+        }
+    }
+    
+    filled_solution("Cul-de-sac Fill", maze, &open, nodes_explored, start_time)
+}
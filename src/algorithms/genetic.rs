@@ -0,0 +1,121 @@
+use std::time::Instant;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::{Cell, Maze, Point, SearchStatus, Solution};
+use super::custom::{get_neighbor_in_direction, is_valid_path};
+use super::{distance_estimate, SearchBudget};
+
+const TOURNAMENT_SIZE: usize = 3;
+const ELITES: usize = 2;
+
+#[derive(Debug, Clone, Copy)]
+pub struct GeneticConfig {
+    pub population: usize,
+    pub generations: usize,
+    pub mutation_rate: f64,
+    pub seed: u64,
+}
+
+impl Default for GeneticConfig {
+    fn default() -> Self {
+        GeneticConfig { population: 100, generations: 200, mutation_rate: 0.02, seed: 42 }
+    }
+}
+
+struct Evaluated {
+    genome: Vec<u8>,
+    walk: Vec<Point>,
+    steps: usize,
+    fitness: (usize, usize),
+}
+
+fn evaluate(maze: &Maze, genome: Vec<u8>) -> Evaluated {
+    let mut walk = vec![maze.start];
+    let mut current = maze.start;
+    let mut closest = (distance_estimate(maze, current), 0);
+    
+    for &gene in &genome {
+        if current == maze.end {
+            break;
+        }
+        match get_neighbor_in_direction(current, gene as usize) {
+            Some(next) if is_valid_path(maze, current, next) => {
+                current = next;
+                walk.push(current);
+                let distance = distance_estimate(maze, current);
+                if distance < closest.0 {
+                    closest = (distance, walk.len() - 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    
+    let steps = walk.len() - 1;
+    walk.truncate(closest.1 + 1);
+    let fitness = (closest.0, closest.1);
+    Evaluated { genome, walk, steps, fitness }
+}
+
+fn tournament<'a>(population: &'a [Evaluated], rng: &mut StdRng) -> &'a Evaluated {
+    (0..TOURNAMENT_SIZE)
+        .map(|_| &population[rng.gen_range(0..population.len())])
+        .min_by_key(|e| e.fitness)
+        .unwrap()
+}
+
+pub fn genetic_solve(maze: &Maze, budget: &SearchBudget, config: &GeneticConfig) -> (Solution, Vec<f64>) {
+    let start_time = Instant::now();
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let genome_length = maze.grid.iter().flatten().filter(|&&c| c != Cell::Wall).count();
+    let population_size = config.population.max(ELITES + 1);
+    let mut nodes_explored = 0;
+    let mut curve = Vec::with_capacity(config.generations);
+    let mut status = None;
+    
+    let mut population: Vec<Evaluated> = (0..population_size)
+        .map(|_| {
+            let genome = (0..genome_length).map(|_| rng.gen_range(0..4)).collect();
+            evaluate(maze, genome)
+        })
+        .collect();
+    
+    for generation in 1..=config.generations {
+        population.sort_by_key(|e| e.fitness);
+        nodes_explored += population.iter().map(|e| e.steps).sum::<usize>();
+        curve.push(population[0].fitness.0 as f64);
+        
+        if population[0].fitness.0 == 0 || generation == config.generations {
+            break;
+        }
+        if let Some(stop) = budget.check(nodes_explored, start_time) {
+            status = Some(stop);
+            break;
+        }
+        
+        let mut next = Vec::with_capacity(population_size);
+        while next.len() < population_size - ELITES {
+            let a = tournament(&population, &mut rng);
+            let b = tournament(&population, &mut rng);
+            let cut = rng.gen_range(0..genome_length);
+            let genome = a.genome[..cut].iter()
+                .chain(&b.genome[cut..])
+                .map(|&gene| if rng.gen_bool(config.mutation_rate) { rng.gen_range(0..4) } else { gene })
+                .collect();
+            next.push(evaluate(maze, genome));
+        }
+        population.truncate(ELITES);
+        population.extend(next);
+    }
+    
+    population.sort_by_key(|e| e.fitness);
+    let best = population.swap_remove(0);
+    let status = status.unwrap_or(if best.fitness.0 == 0 {
+        SearchStatus::Solved
+    } else {
+        SearchStatus::NoPath
+    });
+    
+    let solution = Solution::new("Genetic".to_string(), best.walk, nodes_explored, start_time.elapsed().as_micros(), status);
+    (solution, curve)
+}
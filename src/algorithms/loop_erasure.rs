@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+use crate::{Maze, Point};
+
+pub fn is_walk(maze: &Maze, path: &[Point]) -> bool {
+    path.windows(2).all(|w| {
+        w[0].x.abs_diff(w[1].x) + w[0].y.abs_diff(w[1].y) == 1 || maze.teleports.get(&w[0]) == Some(&w[1])
+    })
+}
+
+pub fn erase_loops(walk: &[Point]) -> Vec<Point> {
+    let mut route: Vec<Point> = Vec::new();
+    let mut position: HashMap<Point, usize> = HashMap::new();
+    
+    for &point in walk {
+        if let Some(&index) = position.get(&point) {
+            for removed in route.drain(index + 1..) {
+                position.remove(&removed);
+            }
+        } else {
+            position.insert(point, route.len());
+            route.push(point);
+        }
+    }
+    
+    route
+}
+
+pub fn shortcut(maze: &Maze, route: &[Point]) -> Vec<Point> {
+    let position: HashMap<Point, usize> = route.iter()
+        .enumerate()
+        .map(|(i, &p)| (p, i))
+        .collect();
+    
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < route.len() {
+        result.push(route[i]);
+        i = maze.get_neighbors(route[i])
+            .iter()
+            .filter_map(|n| position.get(n).copied())
+            .filter(|&j| j > i)
+            .max()
+            .unwrap_or(route.len());
+    }
+    
+    result
+}
+
+pub fn simplify_walk(maze: &Maze, walk: &[Point]) -> Vec<Point> {
+    if !is_walk(maze, walk) {
+        return walk.to_vec();
+    }
+    shortcut(maze, &erase_loops(walk))
+}
@@ -1,14 +1,99 @@
+use std::collections::HashMap;
+use crate::Point;
+
+pub mod budget;
 pub mod bfs;
 pub mod dfs;
 pub mod astar;
 pub mod dijkstra;
 pub mod random;
 pub mod custom;
+pub mod pledge;
+pub mod tremaux;
+pub mod filling;
+pub mod weighted;
+pub mod dstar_lite;
+pub mod turn_aware;
+pub mod loop_erasure;
+pub mod q_learning;
+pub mod genetic;
+pub mod ant_colony;
 
+pub use budget::SearchBudget;
 pub use bfs::bfs_solve;
 pub use dfs::dfs_solve;
 pub use astar::astar_solve;
 pub use dijkstra::dijkstra_solve;
 pub use random::random_solve;
 pub use custom::custom_solve;
+pub use pledge::pledge_solve;
+pub use tremaux::tremaux_solve;
+pub use filling::{cul_de_sac_fill_solve, dead_end_fill_solve};
+pub use weighted::{ara_star_solve, greedy_solve, weighted_astar_solve};
+pub use dstar_lite::dstar_lite_solve;
+pub use turn_aware::{turn_aware_solve, TurnCosts};
+pub use loop_erasure::simplify_walk;
+pub use q_learning::{q_learning_solve, LearningConfig};
+pub use genetic::{genetic_solve, GeneticConfig};
+pub use ant_colony::{ant_colony_solve, AntConfig};
+
+pub type Solver = fn(&crate::Maze, &SearchBudget) -> crate::Solution;
+
+pub fn reconstruct_path(parent: &HashMap<Point, Point>, start: Point, end: Point) -> Vec<Point> {
+    let mut path = Vec::new();
+    let mut node = end;
+    while node != start {
+        path.push(node);
+        node = parent[&node];
+    }
+    path.push(start);
+    path.reverse();
+    path
+}
+
+pub fn reconstruct_states<S: Copy + Eq + std::hash::Hash>(parent: &HashMap<(Point, S), (Point, S)>, start: (Point, S), end: (Point, S)) -> Vec<Point> {
+    let mut path = Vec::new();
+    let mut state = end;
+    while state != start {
+        path.push(state.0);
+        state = parent[&state];
+    }
+    path.push(start.0);
+    path.reverse();
+    path
+}
+
+pub fn manhattan(a: Point, b: Point) -> usize {
+    a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+}
+
+pub fn teleport_distance(maze: &crate::Maze, from: Point, to: Point) -> usize {
+    let to_pad = maze.teleports.keys().map(|&pad| manhattan(from, pad)).min();
+    let from_exit = maze.teleports.values().map(|&exit| manhattan(exit, to)).min();
+    match (to_pad, from_exit) {
+        (Some(a), Some(b)) => manhattan(from, to).min(a + 1 + b),
+        _ => manhattan(from, to),
+    }
+}
+
+pub fn distance_estimate(maze: &crate::Maze, p: Point) -> usize {
+    teleport_distance(maze, p, maze.end)
+}
+
+pub fn direction_between(from: Point, to: Point) -> usize {
+    if to.y < from.y {
+        0
+    } else if to.x > from.x {
+        1
+    } else if to.y > from.y {
+        2
+    } else {
+        3
+    }
+}
 
+pub fn count_turns(path: &[Point]) -> usize {
+    path.windows(3)
+        .filter(|w| direction_between(w[0], w[1]) != direction_between(w[1], w[2]))
+        .count()
+}
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+use std::time::Instant;
+use crate::{Maze, SearchStatus, Solution};
+use super::custom::{get_neighbor_in_direction, is_valid_path};
+use super::SearchBudget;
+
+pub fn pledge_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
+    let start_time = Instant::now();
+    let budget = budget.capped(4 * maze.width * maze.height);
+    let mut path = vec![maze.start];
+    let mut visited = HashSet::new();
+    visited.insert(maze.start);
+    let mut nodes_explored = 1;
+    
+    let dx = maze.end.x as i32 - maze.start.x as i32;
+    let dy = maze.end.y as i32 - maze.start.y as i32;
+    let preferred = if dx.abs() >= dy.abs() {
+        if dx >= 0 { 1 } else { 3 }
+    } else if dy >= 0 {
+        2
+    } else {
+        0
+    };
+    
+    let mut direction = preferred;
+    let mut turns: i32 = 0;
+    let mut following = false;
+    let mut current = maze.start;
+    
+    let mut iterations = 0;
+    let mut status = SearchStatus::Solved;
+    
+    while current != maze.end {
+        if let Some(stop) = budget.check(iterations, start_time) {
+            status = stop;
+            break;
+        }
+        
+        iterations += 1;
+        
+        let front = get_neighbor_in_direction(current, direction)
+            .filter(|&p| is_valid_path(maze, current, p));
+        
+        if !following {
+            if let Some(next) = front {
+                current = next;
+            } else {
+                following = true;
+                direction = (direction + 3) % 4;
+                turns -= 1;
+                continue;
+            }
+        } else if turns == 0 {
+            following = false;
+            continue;
+        } else {
+            let right_dir = (direction + 1) % 4;
+            let right = get_neighbor_in_direction(current, right_dir)
+                .filter(|&p| is_valid_path(maze, current, p));
+            
+            if let Some(next) = right {
+                direction = right_dir;
+                turns += 1;
+                current = next;
+            } else if let Some(next) = front {
+                current = next;
+            } else {
+                direction = (direction + 3) % 4;
+                turns -= 1;
+                continue;
+            }
+        }
+        
+        if visited.insert(current) {
+            nodes_explored += 1;
+        }
+        path.push(current);
+    }
+    
+    Solution::new("Pledge".to_string(), path, nodes_explored, start_time.elapsed().as_micros(), status)
+}
@@ -0,0 +1,108 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::{Cell, Maze, Point, SearchStatus, Solution};
+use super::SearchBudget;
+
+#[derive(Debug, Clone, Copy)]
+pub struct LearningConfig {
+    pub episodes: usize,
+    pub alpha: f64,
+    pub gamma: f64,
+    pub epsilon: f64,
+    pub seed: u64,
+}
+
+impl Default for LearningConfig {
+    fn default() -> Self {
+        LearningConfig { episodes: 500, alpha: 0.5, gamma: 0.99, epsilon: 0.2, seed: 42 }
+    }
+}
+
+fn best_value(q: &HashMap<(Point, Point), f64>, maze: &Maze, state: Point) -> f64 {
+    if state == maze.end {
+        return 0.0;
+    }
+    maze.get_neighbors(state).into_iter()
+        .map(|next| q.get(&(state, next)).copied().unwrap_or(0.0))
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+fn greedy_action(q: &HashMap<(Point, Point), f64>, maze: &Maze, state: Point) -> Option<Point> {
+    maze.get_neighbors(state).into_iter()
+        .max_by(|a, b| {
+            let qa = q.get(&(state, *a)).copied().unwrap_or(0.0);
+            let qb = q.get(&(state, *b)).copied().unwrap_or(0.0);
+            qa.total_cmp(&qb)
+        })
+}
+
+fn greedy_path(q: &HashMap<(Point, Point), f64>, maze: &Maze) -> Vec<Point> {
+    let mut path = vec![maze.start];
+    let mut visited = HashSet::new();
+    visited.insert(maze.start);
+    let mut current = maze.start;
+    while current != maze.end {
+        match greedy_action(q, maze, current) {
+            Some(next) if visited.insert(next) => {
+                path.push(next);
+                current = next;
+            }
+            _ => break,
+        }
+    }
+    path
+}
+
+pub fn q_learning_solve(maze: &Maze, budget: &SearchBudget, config: &LearningConfig) -> (Solution, Vec<usize>) {
+    let start_time = Instant::now();
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let open_cells = maze.grid.iter().flatten().filter(|&&c| c != Cell::Wall).count();
+    let step_limit = 4 * open_cells;
+    let mut q: HashMap<(Point, Point), f64> = HashMap::new();
+    let mut curve = Vec::with_capacity(config.episodes);
+    let mut env_steps = 0;
+    let mut stopped = None;
+    
+    'episodes: for _ in 0..config.episodes {
+        let mut state = maze.start;
+        let mut steps = 0;
+        while state != maze.end && steps < step_limit {
+            if let Some(status) = budget.check(env_steps, start_time) {
+                stopped = Some(status);
+                curve.push(steps);
+                break 'episodes;
+            }
+            
+            let neighbors = maze.get_neighbors(state);
+            if neighbors.is_empty() {
+                break;
+            }
+            let action = if rng.gen_bool(config.epsilon) {
+                neighbors[rng.gen_range(0..neighbors.len())]
+            } else {
+                greedy_action(&q, maze, state).unwrap()
+            };
+            
+            let target = -1.0 + config.gamma * best_value(&q, maze, action);
+            let value = q.entry((state, action)).or_insert(0.0);
+            *value += config.alpha * (target - *value);
+            
+            state = action;
+            steps += 1;
+            env_steps += 1;
+        }
+        curve.push(steps);
+    }
+    
+    let path = greedy_path(&q, maze);
+    let status = stopped.unwrap_or(if path.last() == Some(&maze.end) {
+        SearchStatus::Solved
+    } else {
+        SearchStatus::NoPath
+    });
+    
+    let solution = Solution::new("Q-Learning".to_string(), path, env_steps, start_time.elapsed().as_micros(), status);
+    (solution, curve)
+}
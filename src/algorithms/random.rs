@@ -1,20 +1,27 @@
 use std::collections::HashSet;
 use std::time::Instant;
 use rand::Rng;
-use crate::{Maze, Point, Solution};
+use crate::{Maze, Point, SearchStatus, Solution};
+use super::SearchBudget;
 
-pub fn random_solve(maze: &Maze) -> Solution {
+pub fn random_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
     let start_time = Instant::now();
+    let budget = budget.capped(4 * maze.width * maze.height);
     let mut rng = rand::thread_rng();
     let mut current = maze.start;
     let mut path = vec![maze.start];
     let mut visited = HashSet::new();
     visited.insert(maze.start);
     let mut nodes_explored = 1;
-    let max_iterations = maze.width * maze.height * 10;
     let mut iterations = 0;
+    let mut status = SearchStatus::Solved;
     
-    while current != maze.end && iterations < max_iterations {
+    while current != maze.end {
+        if let Some(stop) = budget.check(iterations, start_time) {
+            status = stop;
+            break;
+        }
+        
         iterations += 1;
         let neighbors: Vec<Point> = maze.get_neighbors(current)
             .into_iter()
@@ -26,6 +33,7 @@ pub fn random_solve(maze: &Maze) -> Solution {
                 path.pop();
                 current = *path.last().unwrap();
             } else {
+                status = SearchStatus::NoPath;
                 break;
             }
         } else {
@@ -37,12 +45,6 @@ pub fn random_solve(maze: &Maze) -> Solution {
         }
     }
     
-    Solution {
-        algorithm: "Random".to_string(),
-        path,
-        path_length: 0,
-        nodes_explored,
-        time_taken: start_time.elapsed().as_micros(),
-    }
+    Solution::new("Random".to_string(), path, nodes_explored, start_time.elapsed().as_micros(), status)
 }
 
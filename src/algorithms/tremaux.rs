@@ -0,0 +1,65 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+use crate::{Maze, Point, SearchStatus, Solution};
+use super::SearchBudget;
+
+fn passage(a: Point, b: Point) -> (Point, Point) {
+    if (a.y, a.x) <= (b.y, b.x) { (a, b) } else { (b, a) }
+}
+
+pub fn tremaux_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
+    let start_time = Instant::now();
+    let mut path = vec![maze.start];
+    let mut visited = HashSet::new();
+    visited.insert(maze.start);
+    let mut nodes_explored = 1;
+    
+    let mut marks: HashMap<(Point, Point), u8> = HashMap::new();
+    let mut current = maze.start;
+    let mut previous: Option<Point> = None;
+    let mut revisited = false;
+    
+    let mut iterations = 0;
+    let mut status = SearchStatus::Solved;
+    
+    while current != maze.end {
+        if let Some(stop) = budget.check(iterations, start_time) {
+            status = stop;
+            break;
+        }
+        
+        iterations += 1;
+        
+        let mark_count = |a: Point, b: Point| *marks.get(&passage(a, b)).unwrap_or(&0);
+        let neighbors = maze.get_neighbors(current);
+        
+        let came_fresh = previous.is_some_and(|p| mark_count(p, current) == 1);
+        
+        let next = if revisited && came_fresh {
+            previous
+        } else {
+            neighbors.iter()
+                .copied()
+                .filter(|&n| mark_count(current, n) == 0)
+                .chain(neighbors.iter().copied().filter(|&n| mark_count(current, n) == 1))
+                .next()
+        };
+        
+        let Some(next) = next else {
+            status = SearchStatus::NoPath;
+            break;
+        };
+        
+        *marks.entry(passage(current, next)).or_insert(0) += 1;
+        previous = Some(current);
+        current = next;
+        
+        revisited = !visited.insert(current);
+        if !revisited {
+            nodes_explored += 1;
+        }
+        path.push(current);
+    }
+    
+    Solution::new("Tremaux".to_string(), path, nodes_explored, start_time.elapsed().as_micros(), status)
+}
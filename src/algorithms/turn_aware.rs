@@ -0,0 +1,97 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::time::Instant;
+use crate::{Maze, Point, SearchStatus, Solution};
+use super::{direction_between, SearchBudget};
+
+const NO_HEADING: usize = 4;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TurnCosts {
+    pub straight: usize,
+    pub turn: usize,
+    pub u_turn: usize,
+}
+
+impl Default for TurnCosts {
+    fn default() -> Self {
+        TurnCosts { straight: 1, turn: 2, u_turn: 4 }
+    }
+}
+
+impl TurnCosts {
+    fn step_cost(&self, heading: usize, direction: usize) -> usize {
+        if heading == NO_HEADING || heading == direction {
+            self.straight
+        } else if (heading + 2) % 4 == direction {
+            self.straight + self.u_turn
+        } else {
+            self.straight + self.turn
+        }
+    }
+}
+
+type State = (Point, usize);
+
+fn state_path(parent: &HashMap<State, State>, end: State) -> Vec<Point> {
+    let mut path = vec![end.0];
+    let mut state = end;
+    while let Some(&previous) = parent.get(&state) {
+        path.push(previous.0);
+        state = previous;
+    }
+    path.reverse();
+    path
+}
+
+pub fn turn_aware_solve(maze: &Maze, budget: &SearchBudget, costs: TurnCosts) -> Solution {
+    let start_time = Instant::now();
+    let algorithm = "Turn-Aware".to_string();
+    let mut open_set = BinaryHeap::new();
+    let mut cost: HashMap<State, usize> = HashMap::new();
+    let mut parent: HashMap<State, State> = HashMap::new();
+    let mut nodes_explored = 0;
+    
+    let start = (maze.start, NO_HEADING);
+    let mut last = start;
+    cost.insert(start, 0);
+    open_set.push(Reverse((0, maze.start.y, maze.start.x, NO_HEADING)));
+    
+    while let Some(Reverse((current_cost, y, x, heading))) = open_set.pop() {
+        let state = (Point { x, y }, heading);
+        if current_cost > cost[&state] {
+            continue;
+        }
+        
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return Solution::new(algorithm, state_path(&parent, last), nodes_explored, start_time.elapsed().as_micros(), status);
+        }
+        
+        nodes_explored += 1;
+        last = state;
+        
+        if state.0 == maze.end {
+            return Solution::new(
+                algorithm,
+                state_path(&parent, state),
+                nodes_explored,
+                start_time.elapsed().as_micros(),
+                SearchStatus::Solved,
+            );
+        }
+        
+        for neighbor in maze.get_neighbors(state.0) {
+            let direction = direction_between(state.0, neighbor);
+            let next = (neighbor, direction);
+            let next_cost = current_cost + costs.step_cost(heading, direction);
+            
+            if next_cost < *cost.get(&next).unwrap_or(&usize::MAX) {
+                cost.insert(next, next_cost);
+                parent.insert(next, state);
+                open_set.push(Reverse((next_cost, neighbor.y, neighbor.x, direction)));
+            }
+        }
+    }
+    
+    Solution::new(algorithm, Vec::new(), nodes_explored, start_time.elapsed().as_micros(), SearchStatus::NoPath)
+}
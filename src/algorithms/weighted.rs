@@ -0,0 +1,188 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::time::Instant;
+use crate::{Maze, Point, SearchStatus, Solution};
+use super::{distance_estimate, reconstruct_path, SearchBudget};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OpenEntry {
+    f: f64,
+    g: usize,
+    point: Point,
+}
+
+impl Eq for OpenEntry {}
+
+impl Ord for OpenEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f.total_cmp(&self.f).then_with(|| self.g.cmp(&other.g))
+    }
+}
+
+impl PartialOrd for OpenEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn best_first(maze: &Maze, budget: &SearchBudget, algorithm: String, g_weight: f64, h_weight: f64, bound: Option<f64>) -> Solution {
+    let start_time = Instant::now();
+    let mut open_set = BinaryHeap::new();
+    let mut came_from = HashMap::new();
+    let mut g_score: HashMap<Point, usize> = HashMap::new();
+    let mut closed = HashSet::new();
+    let mut nodes_explored = 0;
+    let mut last = maze.start;
+    
+    let f_value = |g: usize, p: Point| g_weight * g as f64 + h_weight * distance_estimate(maze, p) as f64;
+    
+    g_score.insert(maze.start, 0);
+    open_set.push(OpenEntry { f: f_value(0, maze.start), g: 0, point: maze.start });
+    
+    while let Some(OpenEntry { g, point: current, .. }) = open_set.pop() {
+        if g != g_score[&current] || !closed.insert(current) {
+            continue;
+        }
+        
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return Solution::new(
+                algorithm,
+                reconstruct_path(&came_from, maze.start, last),
+                nodes_explored,
+                start_time.elapsed().as_micros(),
+                status,
+            ).with_bound(bound);
+        }
+        
+        nodes_explored += 1;
+        last = current;
+        
+        if current == maze.end {
+            return Solution::new(
+                algorithm,
+                reconstruct_path(&came_from, maze.start, current),
+                nodes_explored,
+                start_time.elapsed().as_micros(),
+                SearchStatus::Solved,
+            ).with_bound(bound);
+        }
+        
+        for neighbor in maze.get_neighbors(current) {
+            let tentative_g = g + 1;
+            if !closed.contains(&neighbor) && tentative_g < *g_score.get(&neighbor).unwrap_or(&usize::MAX) {
+                came_from.insert(neighbor, current);
+                g_score.insert(neighbor, tentative_g);
+                open_set.push(OpenEntry { f: f_value(tentative_g, neighbor), g: tentative_g, point: neighbor });
+            }
+        }
+    }
+    
+    Solution::new(algorithm, Vec::new(), nodes_explored, start_time.elapsed().as_micros(), SearchStatus::NoPath).with_bound(bound)
+}
+
+pub fn greedy_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
+    best_first(maze, budget, "Greedy".to_string(), 0.0, 1.0, None)
+}
+
+pub fn weighted_astar_solve(maze: &Maze, budget: &SearchBudget, weight: f64) -> Solution {
+    let weight = weight.max(1.0);
+    best_first(maze, budget, format!("WA* (w={weight:.2})"), 1.0, weight, Some(weight))
+}
+
+pub fn ara_star_solve(maze: &Maze, budget: &SearchBudget, initial_weight: f64) -> Vec<Solution> {
+    let start_time = Instant::now();
+    let mut weight = initial_weight.max(1.0);
+    let mut came_from = HashMap::new();
+    let mut g_score: HashMap<Point, usize> = HashMap::new();
+    let mut open_members: HashSet<Point> = HashSet::new();
+    let mut open_set = BinaryHeap::new();
+    let mut closed: HashSet<Point> = HashSet::new();
+    let mut inconsistent: HashSet<Point> = HashSet::new();
+    let mut nodes_explored = 0;
+    let mut last = maze.start;
+    let mut solutions = Vec::new();
+    
+    let h = |p: Point| distance_estimate(maze, p) as f64;
+    
+    g_score.insert(maze.start, 0);
+    open_members.insert(maze.start);
+    open_set.push(OpenEntry { f: weight * h(maze.start), g: 0, point: maze.start });
+    
+    loop {
+        let mut stop = None;
+        
+        loop {
+            let goal_f = g_score.get(&maze.end).map_or(f64::INFINITY, |&g| g as f64);
+            
+            let Some(&top) = open_set.peek() else { break };
+            if !open_members.contains(&top.point) || top.g != g_score[&top.point] {
+                open_set.pop();
+                continue;
+            }
+            if goal_f <= top.f {
+                break;
+            }
+            
+            if let Some(status) = budget.check(nodes_explored, start_time) {
+                stop = Some(status);
+                break;
+            }
+            
+            open_set.pop();
+            open_members.remove(&top.point);
+            closed.insert(top.point);
+            nodes_explored += 1;
+            last = top.point;
+            
+            for neighbor in maze.get_neighbors(top.point) {
+                let tentative_g = top.g + 1;
+                if tentative_g < *g_score.get(&neighbor).unwrap_or(&usize::MAX) {
+                    came_from.insert(neighbor, top.point);
+                    g_score.insert(neighbor, tentative_g);
+                    if closed.contains(&neighbor) {
+                        inconsistent.insert(neighbor);
+                    } else {
+                        open_members.insert(neighbor);
+                        open_set.push(OpenEntry { f: tentative_g as f64 + weight * h(neighbor), g: tentative_g, point: neighbor });
+                    }
+                }
+            }
+        }
+        
+        let goal_g = g_score.get(&maze.end).copied();
+        let lower_bound = open_members.iter()
+            .chain(inconsistent.iter())
+            .map(|p| g_score[p] as f64 + h(*p))
+            .fold(f64::INFINITY, f64::min);
+        let achieved_bound = match goal_g {
+            Some(g) => weight.min(g as f64 / lower_bound.min(g as f64)),
+            None => weight,
+        };
+        
+        let (path, status) = match (stop, goal_g) {
+            (Some(_), _) if !solutions.is_empty() => return solutions,
+            (Some(status), _) => (reconstruct_path(&came_from, maze.start, last), status),
+            (None, Some(_)) => (reconstruct_path(&came_from, maze.start, maze.end), SearchStatus::Solved),
+            (None, None) => (Vec::new(), SearchStatus::NoPath),
+        };
+        
+        solutions.push(Solution::new(
+            format!("ARA* (w={weight:.2})"),
+            path,
+            nodes_explored,
+            start_time.elapsed().as_micros(),
+            status,
+        ).with_bound(Some(achieved_bound)));
+        
+        if status != SearchStatus::Solved || achieved_bound <= 1.0 {
+            return solutions;
+        }
+        
+        weight = (weight - 0.5).max(1.0);
+        open_members.extend(inconsistent.drain());
+        open_set = open_members.iter()
+            .map(|&p| OpenEntry { f: g_score[&p] as f64 + weight * h(p), g: g_score[&p], point: p })
+            .collect();
+        closed.clear();
+    }
+}
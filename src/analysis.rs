@@ -0,0 +1,287 @@
+use std::collections::{HashMap, HashSet};
+use crate::algorithms::{bfs_solve, count_turns, SearchBudget};
+use crate::{Cell, Maze, Point, SearchStatus};
+
+pub struct MazeReport {
+    pub open_cells: usize,
+    pub dead_ends: usize,
+    pub junctions: HashMap<usize, usize>,
+    pub corridors: usize,
+    pub average_corridor: f64,
+    pub solution_length: Option<usize>,
+    pub decision_points: usize,
+    pub straightness: f64,
+    pub river_factor: f64,
+    pub difficulty: f64,
+}
+
+pub struct UndirectedGraph {
+    cells: Vec<Point>,
+    index: HashMap<Point, usize>,
+    edges: usize,
+    adjacency: Vec<Vec<usize>>,
+}
+
+pub fn undirected_graph(maze: &Maze) -> UndirectedGraph {
+    let mut cells: Vec<Point> = (0..maze.height)
+        .flat_map(|y| (0..maze.width).map(move |x| Point { x, y }))
+        .filter(|p| !matches!(maze.grid[p.y][p.x], Cell::Wall | Cell::Door(_)))
+        .collect();
+    if let Some(i) = cells.iter().position(|&p| p == maze.start) {
+        cells.swap(0, i);
+    }
+    let index: HashMap<Point, usize> = cells.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+    
+    let mut edge_set = HashSet::new();
+    for (i, &p) in cells.iter().enumerate() {
+        for n in maze.get_neighbors(p) {
+            let j = index[&n];
+            edge_set.insert((i.min(j), i.max(j)));
+        }
+    }
+    let mut adjacency = vec![Vec::new(); cells.len()];
+    for &(a, b) in &edge_set {
+        adjacency[a].push(b);
+        adjacency[b].push(a);
+    }
+    
+    UndirectedGraph { cells, index, edges: edge_set.len(), adjacency }
+}
+
+fn degrees(graph: &UndirectedGraph) -> HashMap<Point, usize> {
+    graph.cells.iter()
+        .zip(&graph.adjacency)
+        .map(|(&p, neighbors)| (p, neighbors.len()))
+        .collect()
+}
+
+fn corridor_lengths(graph: &UndirectedGraph) -> Vec<usize> {
+    let degree = |i: usize| graph.adjacency[i].len();
+    let mut lengths = Vec::new();
+    for node in (0..graph.cells.len()).filter(|&i| degree(i) != 2) {
+        for &first in &graph.adjacency[node] {
+            let mut previous = node;
+            let mut current = first;
+            let mut length = 1;
+            while degree(current) == 2 {
+                let Some(&next) = graph.adjacency[current].iter().find(|&&n| n != previous) else {
+                    break;
+                };
+                previous = current;
+                current = next;
+                length += 1;
+            }
+            lengths.push(length);
+        }
+    }
+    lengths
+}
+
+pub fn analyze(maze: &Maze, graph: &UndirectedGraph) -> MazeReport {
+    let degree = degrees(graph);
+    let open_cells = degree.len();
+    let dead_ends = degree.values().filter(|&&d| d == 1).count();
+    let mut junctions = HashMap::new();
+    for &d in degree.values().filter(|&&d| d >= 3) {
+        *junctions.entry(d).or_insert(0) += 1;
+    }
+    
+    let lengths = corridor_lengths(graph);
+    let average_corridor = if lengths.is_empty() {
+        0.0
+    } else {
+        lengths.iter().sum::<usize>() as f64 / lengths.len() as f64
+    };
+    let river_factor = degree.values().filter(|&&d| d == 2).count() as f64 / open_cells.max(1) as f64;
+    
+    let solution = bfs_solve(maze, &SearchBudget::unlimited()).path;
+    let solved = solution.last() == Some(&maze.end);
+    let moves = solution.len().saturating_sub(1);
+    let decision_points = solution.iter()
+        .filter(|&&p| p != maze.end && degree.get(&p).is_some_and(|&d| d >= 3))
+        .count();
+    let straightness = if moves > 1 {
+        1.0 - count_turns(&solution) as f64 / (moves - 1) as f64
+    } else {
+        1.0
+    };
+    
+    let difficulty = if solved {
+        let branching = (2.0 * decision_points as f64 / solution.len() as f64).min(1.0);
+        let coverage = solution.len() as f64 / open_cells.max(1) as f64;
+        let dead_end_density = (10.0 * dead_ends as f64 / open_cells.max(1) as f64).min(1.0);
+        100.0 * (0.3 * branching + 0.3 * (1.0 - straightness) + 0.2 * coverage + 0.2 * dead_end_density)
+    } else {
+        0.0
+    };
+    
+    MazeReport {
+        open_cells,
+        dead_ends,
+        junctions,
+        corridors: lengths.len() / 2,
+        average_corridor,
+        solution_length: solved.then_some(solution.len()),
+        decision_points,
+        straightness,
+        river_factor,
+        difficulty,
+    }
+}
+
+pub fn print_report(maze: &Maze, report: &MazeReport) {
+    let area = maze.width * maze.height;
+    let mut degrees: Vec<(&usize, &usize)> = report.junctions.iter().collect();
+    degrees.sort();
+    let junctions: Vec<String> = degrees.iter().map(|(d, n)| format!("{} of degree {}", n, d)).collect();
+    
+    println!("\n=== MAZE ANALYSIS ({}x{}) ===", maze.width, maze.height);
+    println!("{:<28} {}", "Open cells:", report.open_cells);
+    println!("{:<28} {}", "Dead ends:", report.dead_ends);
+    println!("{:<28} {}", "Junctions:", if junctions.is_empty() { "none".to_string() } else { junctions.join(", ") });
+    println!("{:<28} {} (average length {:.2})", "Corridors:", report.corridors, report.average_corridor);
+    match report.solution_length {
+        Some(length) => {
+            println!("{:<28} {} cells ({:.1}% of area, {:.1}% of open cells)", "Solution length:",
+                     length,
+                     100.0 * length as f64 / area as f64,
+                     100.0 * length as f64 / report.open_cells.max(1) as f64);
+            println!("{:<28} {}", "Decision points on solution:", report.decision_points);
+            println!("{:<28} {:.2}", "Straightness:", report.straightness);
+        }
+        None => println!("{:<28} no route", "Solution length:"),
+    }
+    println!("{:<28} {:.2}", "River factor:", report.river_factor);
+    println!("{:<28} {:.1} / 100", "Difficulty:", report.difficulty);
+}
+
+pub struct ConnectivityReport {
+    pub components: usize,
+    pub largest_component: usize,
+    pub cells: usize,
+    pub edges: usize,
+    pub cycles: usize,
+    pub perfect: bool,
+    pub articulation_points: usize,
+    pub bridges: usize,
+    pub start_end_connected: bool,
+    pub start_reaches_end: bool,
+    pub one_way: bool,
+    pub must_pass_cells: Vec<Point>,
+    pub must_pass_corridors: usize,
+}
+
+pub fn connectivity(maze: &Maze, graph: &UndirectedGraph) -> ConnectivityReport {
+    let UndirectedGraph { cells, index, edges, adjacency } = graph;
+    
+    let unvisited = usize::MAX;
+    let mut discovered = vec![unvisited; cells.len()];
+    let mut low = vec![0; cells.len()];
+    let mut parent = vec![unvisited; cells.len()];
+    let mut articulation = vec![false; cells.len()];
+    let mut bridges = 0;
+    let mut timer = 0;
+    let mut component_sizes = Vec::new();
+    
+    for root in 0..cells.len() {
+        if discovered[root] != unvisited {
+            continue;
+        }
+        discovered[root] = timer;
+        low[root] = timer;
+        timer += 1;
+        let first = timer;
+        let mut root_children = 0;
+        let mut stack = vec![(root, 0)];
+        
+        while let Some(&mut (v, ref mut next)) = stack.last_mut() {
+            if let Some(&w) = adjacency[v].get(*next) {
+                *next += 1;
+                if discovered[w] == unvisited {
+                    parent[w] = v;
+                    discovered[w] = timer;
+                    low[w] = timer;
+                    timer += 1;
+                    if v == root {
+                        root_children += 1;
+                    }
+                    stack.push((w, 0));
+                } else if w != parent[v] {
+                    low[v] = low[v].min(discovered[w]);
+                }
+            } else {
+                stack.pop();
+                if let Some(&(p, _)) = stack.last() {
+                    low[p] = low[p].min(low[v]);
+                    if low[v] > discovered[p] {
+                        bridges += 1;
+                    }
+                    if p != root && low[v] >= discovered[p] {
+                        articulation[p] = true;
+                    }
+                }
+            }
+        }
+        if root_children > 1 {
+            articulation[root] = true;
+        }
+        component_sizes.push(timer - first + 1);
+    }
+    
+    let mut must_pass_cells = Vec::new();
+    let mut must_pass_corridors = 0;
+    let start_end_connected = match (index.get(&maze.start), index.get(&maze.end)) {
+        (Some(&start), Some(&end)) => {
+            let mut child = end;
+            while child != start && parent[child] != unvisited {
+                let v = parent[child];
+                if low[child] > discovered[v] {
+                    must_pass_corridors += 1;
+                }
+                if v != start && low[child] >= discovered[v] {
+                    must_pass_cells.push(cells[v]);
+                }
+                child = v;
+            }
+            child == start
+        }
+        _ => false,
+    };
+    
+    let components = component_sizes.len();
+    let cycles = edges + components - cells.len();
+    ConnectivityReport {
+        components,
+        largest_component: component_sizes.iter().copied().max().unwrap_or(0),
+        cells: cells.len(),
+        edges: *edges,
+        cycles,
+        perfect: components == 1 && cycles == 0,
+        articulation_points: articulation.iter().filter(|&&a| a).count(),
+        bridges,
+        start_end_connected,
+        start_reaches_end: bfs_solve(maze, &SearchBudget::unlimited()).status == SearchStatus::Solved,
+        one_way: maze.grid.iter().flatten().any(|c| matches!(c, Cell::OneWay(_))),
+        must_pass_cells,
+        must_pass_corridors,
+    }
+}
+
+pub fn print_connectivity(report: &ConnectivityReport) {
+    println!("\n=== CONNECTIVITY ===");
+    println!("{:<28} {}", "Start reaches end:", if report.start_reaches_end { "yes" } else { "no" });
+    if report.one_way {
+        println!("(Below: the underlying undirected graph, one-way cells ignored.)");
+    }
+    println!("{:<28} {} (largest has {} of {} cells)", "Components:", report.components, report.largest_component, report.cells);
+    println!("{:<28} {} edges, {} independent cycles", "Graph:", report.edges, report.cycles);
+    println!("{:<28} {}", "Perfect maze:", if report.perfect { "yes (spanning tree)" } else { "no" });
+    println!("{:<28} {}", "Articulation points:", report.articulation_points);
+    println!("{:<28} {}", "Bridges:", report.bridges);
+    if report.one_way {
+        println!("{:<28} {}", "Start connected to end:", if report.start_end_connected { "yes" } else { "no" });
+    }
+    if report.start_end_connected {
+        println!("{:<28} {} cells, {} corridors", "Every route passes through:", report.must_pass_cells.len(), report.must_pass_corridors);
+    }
+}
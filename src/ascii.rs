@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::fs;
+use crate::micromouse::load_maz_file;
+use crate::{Cell, Maze, Point};
+
+pub fn parse_ascii(text: &str) -> Result<Maze, String> {
+    let mut lines: Vec<&str> = text.lines()
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    let width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+    if lines.is_empty() || width == 0 {
+        return Err("ASCII maze is empty".to_string());
+    }
+    
+    let mut grid = vec![vec![Cell::Wall; width]; lines.len()];
+    let mut start = None;
+    let mut end = None;
+    let mut pads: HashMap<u8, usize> = HashMap::new();
+    
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            grid[y][x] = match c {
+                '#' => Cell::Wall,
+                ' ' | '.' => Cell::Path,
+                'S' | 'E' => {
+                    let slot = if c == 'S' { &mut start } else { &mut end };
+                    if slot.replace(Point { x, y }).is_some() {
+                        return Err(format!("more than one '{}' in ASCII maze", c));
+                    }
+                    Cell::Path
+                }
+                '0'..='9' => {
+                    let id = c as u8 - b'0';
+                    *pads.entry(id).or_insert(0) += 1;
+                    Cell::Teleport(id)
+                }
+                '^' => Cell::OneWay(0),
+                '>' => Cell::OneWay(1),
+                'v' => Cell::OneWay(2),
+                '<' => Cell::OneWay(3),
+                _ => return Err(format!("unknown symbol '{}' at line {}, column {}", c, y + 1, x + 1)),
+            };
+        }
+    }
+    
+    if let Some((id, count)) = pads.iter().find(|(_, &count)| count != 2) {
+        return Err(format!("teleport {} has {} pad(s); each digit must appear exactly twice", id, count));
+    }
+    
+    let start = start.ok_or("ASCII maze has no start 'S'")?;
+    let end = end.ok_or("ASCII maze has no end 'E'")?;
+    Ok(Maze::from_grid(grid, start, end))
+}
+
+pub fn load_maze_file(path: &str) -> Result<Maze, String> {
+    if path.ends_with(".maz") {
+        return load_maz_file(path).map(|loaded| loaded.maze);
+    }
+    let text = fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+    parse_ascii(&text)
+}
@@ -0,0 +1,105 @@
+use std::time::Instant;
+use rand::Rng;
+use crate::algorithms::{bfs_solve, SearchBudget};
+use crate::analysis::{analyze, print_report, undirected_graph, MazeReport};
+use crate::placement::{get_placement_from_user, place_endpoints, Placement};
+use crate::{Cell, Maze};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Constraints {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub min_difficulty: f64,
+}
+
+impl Constraints {
+    fn shortfall(&self, report: &MazeReport) -> f64 {
+        let length = match report.solution_length {
+            Some(length) => length,
+            None => return f64::INFINITY,
+        };
+        let length_gap = if length < self.min_length {
+            (self.min_length - length) as f64 / self.min_length as f64
+        } else if length > self.max_length {
+            (length - self.max_length) as f64 / self.max_length.max(1) as f64
+        } else {
+            0.0
+        };
+        let difficulty_gap = (self.min_difficulty - report.difficulty).max(0.0) / 100.0;
+        length_gap + difficulty_gap
+    }
+}
+
+fn steer_end(maze: &mut Maze, constraints: &Constraints, rng: &mut impl Rng) -> bool {
+    let route = bfs_solve(maze, &SearchBudget::unlimited()).path;
+    if route.len() <= constraints.max_length || constraints.max_length < constraints.min_length.max(2) {
+        return false;
+    }
+    let length = rng.gen_range(constraints.min_length.max(2)..=constraints.max_length);
+    let abandoned = maze.end;
+    maze.end = route[length - 1];
+    if abandoned.x == 0 || abandoned.y == 0 || abandoned.x == maze.width - 1 || abandoned.y == maze.height - 1 {
+        maze.grid[abandoned.y][abandoned.x] = Cell::Wall;
+    }
+    true
+}
+
+pub fn generate_constrained(width: usize, height: usize, constraints: &Constraints, placement: Placement, max_attempts: usize) -> (Maze, MazeReport, usize, usize, bool) {
+    let mut rng = rand::thread_rng();
+    let mut closest: Option<(Maze, MazeReport, usize)> = None;
+    let mut placement_failures = 0;
+    
+    for attempt in 1..=max_attempts {
+        let mut maze = Maze::new(width, height);
+        if !place_endpoints(&mut maze, placement, &mut rng) {
+            placement_failures += 1;
+        }
+        let mut report = analyze(&maze, &undirected_graph(&maze));
+        if report.solution_length.is_some_and(|length| length > constraints.max_length) && steer_end(&mut maze, constraints, &mut rng) {
+            report = analyze(&maze, &undirected_graph(&maze));
+        }
+        
+        let shortfall = constraints.shortfall(&report);
+        if shortfall == 0.0 {
+            return (maze, report, attempt, placement_failures, true);
+        }
+        if closest.as_ref().is_none_or(|(_, best, _)| shortfall < constraints.shortfall(best)) {
+            closest = Some((maze, report, attempt));
+        }
+    }
+    
+    let (maze, report, _) = closest.unwrap();
+    (maze, report, max_attempts, placement_failures, false)
+}
+
+pub fn run_constrained() {
+    let (width, height) = crate::get_size_from_user();
+    let min_length = crate::prompt_number("Minimum solution length in cells (default: 0)", 0, |_: &usize| true);
+    let max_length = crate::prompt_number("Maximum solution length in cells (default: any)", usize::MAX, |max: &usize| *max >= min_length);
+    let min_difficulty = crate::prompt_number("Minimum difficulty score 0-100 (default: 0)", 0.0, |d: &f64| (0.0..=100.0).contains(d));
+    let placement = get_placement_from_user(width, height);
+    let max_attempts = crate::prompt_number("Maximum generation attempts (default: 1000)", 1000, |n: &usize| *n > 0);
+    let show_visualizations = crate::get_display_choice();
+    
+    let constraints = Constraints { min_length, max_length, min_difficulty };
+    println!("\nGenerating {width}x{height} mazes until the constraints are met...");
+    let start_time = Instant::now();
+    let (maze, report, attempts, placement_failures, satisfied) = generate_constrained(width, height, &constraints, placement, max_attempts);
+    let elapsed = start_time.elapsed().as_millis();
+    
+    if placement_failures > 0 {
+        println!("Could not satisfy the placement in {} of {} attempt(s); those mazes kept the corners.", placement_failures, attempts);
+    }
+    if satisfied {
+        println!("Constraints met after {} attempt(s) in {} ms.", attempts, elapsed);
+    } else {
+        println!("No maze met the constraints in {} attempts ({} ms); showing the closest one.", attempts, elapsed);
+    }
+    
+    if show_visualizations {
+        let solution = bfs_solve(&maze, &SearchBudget::unlimited());
+        println!("\n=== Generated Maze ===");
+        maze.display_with_path(&solution.path);
+    }
+    print_report(&maze, &report);
+}
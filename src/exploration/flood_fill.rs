@@ -0,0 +1,41 @@
+use std::collections::VecDeque;
+use crate::Point;
+use super::{Agent, KnownMap};
+
+pub struct FloodFillAgent;
+
+impl FloodFillAgent {
+    fn flood(map: &KnownMap) -> Vec<Vec<usize>> {
+        let mut distance = vec![vec![usize::MAX; map.width]; map.height];
+        let mut queue = VecDeque::new();
+        for &goal in &map.goals {
+            distance[goal.y][goal.x] = 0;
+            queue.push_back(goal);
+        }
+        
+        while let Some(current) = queue.pop_front() {
+            for neighbor in map.neighbors(current, true) {
+                if distance[neighbor.y][neighbor.x] == usize::MAX {
+                    distance[neighbor.y][neighbor.x] = distance[current.y][current.x] + 1;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        
+        distance
+    }
+}
+
+impl Agent for FloodFillAgent {
+    fn name(&self) -> String {
+        "Flood Fill".to_string()
+    }
+    
+    fn next_move(&mut self, position: Point, map: &KnownMap) -> Option<Point> {
+        let distance = Self::flood(map);
+        map.neighbors(position, false)
+            .into_iter()
+            .filter(|p| distance[p.y][p.x] != usize::MAX)
+            .min_by_key(|p| distance[p.y][p.x])
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use crate::Point;
+use super::{Agent, KnownMap};
+
+pub struct FrontierAgent;
+
+fn first_step_toward(position: Point, map: &KnownMap, is_target: impl Fn(Point) -> bool) -> Option<Point> {
+    let mut queue = VecDeque::new();
+    let mut parent: HashMap<Point, Point> = HashMap::new();
+    parent.insert(position, position);
+    queue.push_back(position);
+    
+    while let Some(current) = queue.pop_front() {
+        if current != position && is_target(current) {
+            let mut step = current;
+            while parent[&step] != position {
+                step = parent[&step];
+            }
+            return Some(step);
+        }
+        
+        for neighbor in map.neighbors(current, false) {
+            if let Entry::Vacant(entry) = parent.entry(neighbor) {
+                entry.insert(current);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    
+    None
+}
+
+impl Agent for FrontierAgent {
+    fn name(&self) -> String {
+        "Frontier".to_string()
+    }
+    
+    fn next_move(&mut self, position: Point, map: &KnownMap) -> Option<Point> {
+        first_step_toward(position, map, |p| map.goals.contains(&p))
+            .or_else(|| first_step_toward(position, map, |p| {
+                map.neighbors(p, true).len() > map.neighbors(p, false).len()
+            }))
+    }
+}
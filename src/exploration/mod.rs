@@ -0,0 +1,190 @@
+mod flood_fill;
+mod frontier;
+
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::time::Instant;
+use crate::algorithms::{bfs_solve, simplify_walk, SearchBudget};
+use crate::{Cell, Maze, Point, SearchStatus, Solution};
+
+pub use flood_fill::FloodFillAgent;
+pub use frontier::FrontierAgent;
+
+pub struct KnownMap {
+    pub width: usize,
+    pub height: usize,
+    pub goals: Vec<Point>,
+    cells: Vec<Vec<Option<Cell>>>,
+}
+
+impl KnownMap {
+    pub fn new(maze: &Maze) -> Self {
+        KnownMap {
+            width: maze.width,
+            height: maze.height,
+            goals: vec![maze.end],
+            cells: vec![vec![None; maze.width]; maze.height],
+        }
+    }
+    
+    pub fn get(&self, point: Point) -> Option<Cell> {
+        self.cells[point.y][point.x]
+    }
+    
+    pub fn neighbors(&self, point: Point, include_unknown: bool) -> Vec<Point> {
+        let mut neighbors = Vec::new();
+        let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+        
+        for (dx, dy) in directions.iter() {
+            let x = point.x as i32 + dx;
+            let y = point.y as i32 + dy;
+            
+            if x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32 {
+                let neighbor = Point { x: x as usize, y: y as usize };
+                match self.get(neighbor) {
+                    Some(Cell::Path) => neighbors.push(neighbor),
+                    None if include_unknown => neighbors.push(neighbor),
+                    _ => {}
+                }
+            }
+        }
+        
+        neighbors
+    }
+    
+    pub fn known_count(&self) -> usize {
+        self.cells.iter().flatten().filter(|c| c.is_some()).count()
+    }
+    
+    fn sense(&mut self, maze: &Maze, position: Point, radius: usize) {
+        let mut queue = VecDeque::new();
+        let mut depth = vec![vec![usize::MAX; maze.width]; maze.height];
+        depth[position.y][position.x] = 0;
+        queue.push_back(position);
+        
+        while let Some(current) = queue.pop_front() {
+            self.cells[current.y][current.x] = Some(maze.grid[current.y][current.x]);
+            
+            let x = current.x as i32;
+            let y = current.y as i32;
+            for (nx, ny) in [(x, y + 1), (x + 1, y), (x, y - 1), (x - 1, y)] {
+                if nx < 0 || nx >= maze.width as i32 || ny < 0 || ny >= maze.height as i32 {
+                    continue;
+                }
+                let (nx, ny) = (nx as usize, ny as usize);
+                self.cells[ny][nx] = Some(maze.grid[ny][nx]);
+                
+                let d = depth[current.y][current.x] + 1;
+                if maze.grid[ny][nx] == Cell::Path && d <= radius && depth[ny][nx] == usize::MAX {
+                    depth[ny][nx] = d;
+                    queue.push_back(Point { x: nx, y: ny });
+                }
+            }
+        }
+    }
+}
+
+pub trait Agent {
+    fn name(&self) -> String;
+    fn next_move(&mut self, position: Point, map: &KnownMap) -> Option<Point>;
+}
+
+pub fn explore(maze: &Maze, map: &mut KnownMap, from: Point, agent: &mut dyn Agent, radius: usize, budget: &SearchBudget) -> Solution {
+    let start_time = Instant::now();
+    let mut position = from;
+    let mut traveled = vec![position];
+    let mut status = SearchStatus::Solved;
+    
+    map.sense(maze, position, radius);
+    
+    while !map.goals.contains(&position) {
+        if let Some(stop) = budget.check(traveled.len() - 1, start_time) {
+            status = stop;
+            break;
+        }
+        
+        let next = agent.next_move(position, map);
+        match next {
+            Some(next) if maze.get_neighbors(position).contains(&next) => {
+                position = next;
+                traveled.push(position);
+                map.sense(maze, position, radius);
+            }
+            _ => {
+                status = SearchStatus::NoPath;
+                break;
+            }
+        }
+    }
+    
+    Solution::new(agent.name(), traveled, map.known_count(), start_time.elapsed().as_micros(), status)
+}
+
+pub fn run_agent(maze: &Maze, agent: &mut dyn Agent, radius: usize, budget: &SearchBudget) -> Solution {
+    let mut map = KnownMap::new(maze);
+    explore(maze, &mut map, maze.start, agent, radius, budget)
+}
+
+fn get_radius_from_user() -> usize {
+    let mut input = String::new();
+    loop {
+        print!("\nSensor radius in cells (default: 1, minimum: 1): ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        if input.trim().is_empty() {
+            return 1;
+        }
+        match input.trim().parse::<usize>() {
+            Ok(r) if r >= 1 => return r,
+            _ => println!("Invalid input. Please enter a number of at least 1 or press Enter for default."),
+        }
+    }
+}
+
+pub fn run_exploration() {
+    let (width, height) = crate::get_size_from_user();
+    let radius = get_radius_from_user();
+    let show_visualizations = crate::get_display_choice();
+    let budget = crate::get_budget_from_user();
+    crate::install_cancel_handler(&budget);
+    
+    println!("\nGenerating random maze ({width}x{height})...");
+    let maze = Maze::new(width, height);
+    
+    let shortest = bfs_solve(&maze, &budget).path.len().saturating_sub(1);
+    
+    let mut agents: Vec<Box<dyn Agent>> = vec![
+        Box::new(FloodFillAgent),
+        Box::new(FrontierAgent),
+    ];
+    
+    let runs: Vec<Solution> = agents.iter_mut()
+        .map(|agent| run_agent(&maze, agent.as_mut(), radius, &budget))
+        .collect();
+    
+    if show_visualizations {
+        for run in &runs {
+            println!("\n=== {} Route ({}) ===", run.algorithm, run.status.label());
+            maze.display_with_path(&run.path);
+            println!();
+        }
+    }
+    
+    println!("\n=== EXPLORATION (sensor radius {}, shortest route {} moves) ===", radius, shortest);
+    println!("{:<16} | {:<12} | {:<12} | {:<12} | {:<12} | {:<10}",
+             "Agent", "Distance", "Route", "Cells Seen", "Time (μs)", "Status");
+    println!("{}", "-".repeat(90));
+    
+    for run in &runs {
+        println!("{:<16} | {:<12} | {:<12} | {:<12} | {:<12} | {:<10}",
+                 run.algorithm,
+                 run.path.len() - 1,
+                 simplify_walk(&maze, &run.path).len() - 1,
+                 run.nodes_explored,
+                 run.time_taken,
+                 run.status.label());
+    }
+    println!("{}", "-".repeat(90));
+}
@@ -0,0 +1,226 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::time::Instant;
+use rand::seq::SliceRandom;
+use crate::algorithms::{bfs_solve, direction_between, manhattan, SearchBudget};
+use crate::{Cell, Maze, Point};
+
+const ARROWS: [&str; 4] = ["↑ ", "→ ", "↓ ", "← "];
+const UNREACHABLE: (u8, u8, u8) = (96, 96, 96);
+const WALL: (u8, u8, u8) = (0, 0, 0);
+const CELL_PIXELS: usize = 8;
+
+pub fn distance_field(maze: &Maze, sources: &[Point]) -> HashMap<Point, usize> {
+    let mut incoming: HashMap<Point, Vec<Point>> = HashMap::new();
+    for (y, row) in maze.grid.iter().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            if *cell != Cell::Wall {
+                let from = Point { x, y };
+                for to in maze.get_neighbors(from) {
+                    incoming.entry(to).or_default().push(from);
+                }
+            }
+        }
+    }
+    
+    let mut distance = HashMap::new();
+    let mut queue = VecDeque::new();
+    for &source in sources {
+        if distance.insert(source, 0).is_none() {
+            queue.push_back(source);
+        }
+    }
+    while let Some(current) = queue.pop_front() {
+        for &previous in incoming.get(&current).into_iter().flatten() {
+            if !distance.contains_key(&previous) {
+                distance.insert(previous, distance[&current] + 1);
+                queue.push_back(previous);
+            }
+        }
+    }
+    distance
+}
+
+pub fn flow_field(maze: &Maze, distance: &HashMap<Point, usize>) -> HashMap<Point, Point> {
+    distance.iter()
+        .filter(|(_, &d)| d > 0)
+        .filter_map(|(&p, &d)| {
+            maze.get_neighbors(p).into_iter()
+                .find(|n| distance.get(n) == Some(&(d - 1)))
+                .map(|n| (p, n))
+        })
+        .collect()
+}
+
+pub fn follow_flow(flow: &HashMap<Point, Point>, from: Point) -> Vec<Point> {
+    let mut path = vec![from];
+    let mut current = from;
+    while let Some(&next) = flow.get(&current) {
+        path.push(next);
+        current = next;
+    }
+    path
+}
+
+fn heat_rgb(t: f64) -> (u8, u8, u8) {
+    let stops = [(0.0, (0, 0, 255)), (0.25, (0, 255, 255)), (0.5, (0, 255, 0)), (0.75, (255, 255, 0)), (1.0, (255, 0, 0))];
+    let t = t.clamp(0.0, 1.0);
+    let i = stops.windows(2).position(|w| t <= w[1].0).unwrap_or(stops.len() - 2);
+    let ((t0, a), (t1, b)) = (stops[i], stops[i + 1]);
+    let f = (t - t0) / (t1 - t0);
+    let mix = |x: u8, y: u8| (x as f64 + (y as f64 - x as f64) * f).round() as u8;
+    (mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+}
+
+fn cell_color(maze: &Maze, distance: &HashMap<Point, usize>, max: usize, p: Point) -> (u8, u8, u8) {
+    if maze.grid[p.y][p.x] == Cell::Wall {
+        return WALL;
+    }
+    match distance.get(&p) {
+        Some(&d) => heat_rgb(d as f64 / max.max(1) as f64),
+        None => UNREACHABLE,
+    }
+}
+
+pub fn display_heatmap(maze: &Maze, distance: &HashMap<Point, usize>) {
+    let max = distance.values().copied().max().unwrap_or(0);
+    let mut marks = HashMap::new();
+    for y in 0..maze.height {
+        for x in 0..maze.width {
+            let p = Point { x, y };
+            if maze.grid[y][x] != Cell::Wall {
+                let (r, g, b) = cell_color(maze, distance, max, p);
+                let label = if distance.get(&p) == Some(&0) { "<>" } else { "  " };
+                marks.insert(p, format!("\x1b[48;2;{};{};{}m\x1b[30m{}\x1b[0m", r, g, b, label));
+            }
+        }
+    }
+    maze.display_with_marks(&[], &marks);
+    println!("Scale: blue = 0 steps (sources <>), red = {} steps, gray = cannot reach a source", max);
+}
+
+pub fn display_flow(maze: &Maze, distance: &HashMap<Point, usize>, flow: &HashMap<Point, Point>) {
+    let mut marks = HashMap::new();
+    for (&p, &next) in flow {
+        let mark = if manhattan(p, next) == 1 { ARROWS[direction_between(p, next)] } else { "TT" };
+        marks.insert(p, mark.to_string());
+    }
+    for (&p, &d) in distance {
+        if d == 0 {
+            marks.insert(p, "<>".to_string());
+        }
+    }
+    maze.display_with_marks(&[], &marks);
+}
+
+pub fn write_ppm(maze: &Maze, distance: &HashMap<Point, usize>, path: &str) -> io::Result<()> {
+    let max = distance.values().copied().max().unwrap_or(0);
+    let mut out = BufWriter::new(File::create(path)?);
+    write!(out, "P6\n{} {}\n255\n", maze.width * CELL_PIXELS, maze.height * CELL_PIXELS)?;
+    for y in 0..maze.height {
+        let row: Vec<u8> = (0..maze.width)
+            .flat_map(|x| {
+                let (r, g, b) = cell_color(maze, distance, max, Point { x, y });
+                [r, g, b].repeat(CELL_PIXELS)
+            })
+            .collect();
+        for _ in 0..CELL_PIXELS {
+            out.write_all(&row)?;
+        }
+    }
+    out.flush()
+}
+
+fn get_sources(maze: &Maze) -> Vec<Point> {
+    let mut input = String::new();
+    loop {
+        print!("\nSource cells as x,y separated by spaces (default: maze end): ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        if input.trim().is_empty() {
+            return vec![maze.end];
+        }
+        let sources: Option<Vec<Point>> = input.split_whitespace()
+            .map(|pair| {
+                let (x, y) = pair.split_once(',')?;
+                let p = Point { x: x.trim().parse().ok()?, y: y.trim().parse().ok()? };
+                (p.x < maze.width && p.y < maze.height && maze.grid[p.y][p.x] != Cell::Wall).then_some(p)
+            })
+            .collect();
+        match sources {
+            Some(sources) => return sources,
+            None => println!("Invalid input. Each source must be an open cell like 3,5."),
+        }
+    }
+}
+
+fn get_export_path() -> Option<String> {
+    print!("\nExport the heatmap as a PPM image to (Enter to skip): ");
+    io::stdout().flush().unwrap();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    let path = input.trim();
+    (!path.is_empty()).then(|| path.to_string())
+}
+
+pub fn run_fields() {
+    let maze = crate::get_maze_from_user();
+    let sources = get_sources(&maze);
+    let agent_count = crate::prompt_number("Number of agents routed by the flow field (default: 5)", 5, |_: &usize| true);
+    let show_visualizations = crate::get_display_choice();
+    let export = get_export_path();
+    
+    let start_time = Instant::now();
+    let distance = distance_field(&maze, &sources);
+    let flow = flow_field(&maze, &distance);
+    let field_time = start_time.elapsed().as_micros();
+    
+    if show_visualizations {
+        println!("\n=== Distance Heatmap ===");
+        display_heatmap(&maze, &distance);
+        println!("\n=== Flow Field (<> sources, TT teleport) ===");
+        display_flow(&maze, &distance, &flow);
+    }
+    
+    if let Some(path) = export {
+        match write_ppm(&maze, &distance, &path) {
+            Ok(()) => println!("\nWrote {}x{} heatmap to {}", maze.width * CELL_PIXELS, maze.height * CELL_PIXELS, path),
+            Err(e) => println!("\nCould not write {}: {}", path, e),
+        }
+    }
+    
+    let open = maze.grid.iter().flatten().filter(|&&c| c != Cell::Wall).count();
+    println!("\n=== FIELD SUMMARY ===");
+    println!("Sources: {}", sources.iter().map(|p| format!("({}, {})", p.x, p.y)).collect::<Vec<_>>().join(" "));
+    println!("Cells reaching a source: {} of {}", distance.len(), open);
+    println!("Farthest cell: {} steps", distance.values().max().unwrap_or(&0));
+    println!("Field computed once in {} μs", field_time);
+    
+    let mut cells: Vec<Point> = flow.keys().copied().collect();
+    cells.shuffle(&mut rand::thread_rng());
+    cells.truncate(agent_count);
+    if cells.is_empty() {
+        return;
+    }
+    
+    let reference_start = Instant::now();
+    for &cell in &cells {
+        let mut single = maze.clone();
+        single.start = cell;
+        single.end = sources[0];
+        bfs_solve(&single, &SearchBudget::unlimited());
+    }
+    let reference_time = reference_start.elapsed().as_micros();
+    
+    println!("\n{:<10} | {:<10} | {:<10}", "Agent", "Start", "Steps");
+    println!("{}", "-".repeat(36));
+    for (i, &cell) in cells.iter().enumerate() {
+        let route = follow_flow(&flow, cell);
+        println!("{:<10} | {:<10} | {:<10}", i + 1, format!("({}, {})", cell.x, cell.y), route.len() - 1);
+    }
+    println!("{}", "-".repeat(36));
+    println!("{} separate BFS runs to the first source took {} μs.", cells.len(), reference_time);
+}
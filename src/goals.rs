@@ -0,0 +1,294 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
+use rand::seq::SliceRandom;
+use crate::algorithms::{reconstruct_path, SearchBudget};
+use crate::{Cell, Maze, Point, SearchStatus, Solution};
+
+const EXACT_LIMIT: usize = 12;
+const UNREACHABLE: usize = usize::MAX;
+
+struct Tree {
+    distance: HashMap<Point, usize>,
+    parent: HashMap<Point, Point>,
+}
+
+fn bfs_tree(maze: &Maze, from: Point, budget: &SearchBudget, start_time: Instant, nodes_explored: &mut usize) -> Result<Tree, SearchStatus> {
+    let mut distance = HashMap::new();
+    let mut parent = HashMap::new();
+    let mut queue = VecDeque::new();
+    distance.insert(from, 0);
+    queue.push_back(from);
+    
+    while let Some(current) = queue.pop_front() {
+        if let Some(status) = budget.check(*nodes_explored, start_time) {
+            return Err(status);
+        }
+        *nodes_explored += 1;
+        
+        let next_distance = distance[&current] + 1;
+        for neighbor in maze.get_neighbors(current) {
+            if let Entry::Vacant(entry) = distance.entry(neighbor) {
+                entry.insert(next_distance);
+                parent.insert(neighbor, current);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    
+    Ok(Tree { distance, parent })
+}
+
+fn solution(algorithm: &str, path: Vec<Point>, nodes_explored: usize, start_time: Instant, status: SearchStatus) -> Solution {
+    Solution::new(algorithm.to_string(), path, nodes_explored, start_time.elapsed().as_micros(), status)
+}
+
+pub fn nearest_goal_solve(maze: &Maze, goals: &[Point], budget: &SearchBudget) -> Solution {
+    let start_time = Instant::now();
+    let mut parent = HashMap::new();
+    let mut queue = VecDeque::new();
+    let mut nodes_explored = 0;
+    let mut last = maze.start;
+    parent.insert(maze.start, maze.start);
+    queue.push_back(maze.start);
+    
+    while let Some(current) = queue.pop_front() {
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return solution("Nearest Goal", reconstruct_path(&parent, maze.start, last), nodes_explored, start_time, status);
+        }
+        
+        nodes_explored += 1;
+        last = current;
+        
+        if goals.contains(&current) {
+            let mut found = solution("Nearest Goal", reconstruct_path(&parent, maze.start, current), nodes_explored, start_time, SearchStatus::Solved);
+            found.suboptimality_bound = Some(1.0);
+            return found;
+        }
+        
+        for neighbor in maze.get_neighbors(current) {
+            if let Entry::Vacant(entry) = parent.entry(neighbor) {
+                entry.insert(current);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    
+    solution("Nearest Goal", Vec::new(), nodes_explored, start_time, SearchStatus::NoPath)
+}
+
+fn held_karp(distances: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let end = distances.len() - 1;
+    let count = end - 1;
+    if count == 0 {
+        return Some(vec![0, end]);
+    }
+    
+    let full = (1 << count) - 1;
+    let mut cost = vec![vec![UNREACHABLE; count]; 1 << count];
+    let mut previous = vec![vec![usize::MAX; count]; 1 << count];
+    for i in 0..count {
+        cost[1 << i][i] = distances[0][i + 1];
+    }
+    
+    for mask in 1..=full {
+        for last in 0..count {
+            if mask & (1 << last) == 0 || cost[mask][last] == UNREACHABLE {
+                continue;
+            }
+            for next in 0..count {
+                if mask & (1 << next) != 0 {
+                    continue;
+                }
+                let step = distances[last + 1][next + 1];
+                if step == UNREACHABLE {
+                    continue;
+                }
+                let extended = mask | (1 << next);
+                let candidate = cost[mask][last].saturating_add(step);
+                if candidate < cost[extended][next] {
+                    cost[extended][next] = candidate;
+                    previous[extended][next] = last;
+                }
+            }
+        }
+    }
+    
+    let mut last = (0..count)
+        .min_by_key(|&i| cost[full][i].saturating_add(distances[i + 1][end]))
+        .filter(|&i| cost[full][i].saturating_add(distances[i + 1][end]) != UNREACHABLE)?;
+    let mut mask = full;
+    let mut route = vec![end];
+    loop {
+        route.push(last + 1);
+        let before = previous[mask][last];
+        mask &= !(1 << last);
+        if mask == 0 {
+            break;
+        }
+        last = before;
+    }
+    route.push(0);
+    route.reverse();
+    Some(route)
+}
+
+fn route_cost(distances: &[Vec<usize>], route: &[usize]) -> usize {
+    route.windows(2).fold(0, |total, leg| total.saturating_add(distances[leg[0]][leg[1]]))
+}
+
+fn nearest_neighbor(distances: &[Vec<usize>]) -> Vec<usize> {
+    let end = distances.len() - 1;
+    let mut remaining: Vec<usize> = (1..end).collect();
+    let mut route = vec![0];
+    
+    while !remaining.is_empty() {
+        let current = *route.last().unwrap();
+        let (index, _) = remaining.iter()
+            .enumerate()
+            .min_by_key(|&(_, &w)| distances[current][w])
+            .unwrap();
+        route.push(remaining.swap_remove(index));
+    }
+    
+    route.push(end);
+    route
+}
+
+fn two_opt(distances: &[Vec<usize>], route: &mut [usize]) {
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 1..route.len() - 2 {
+            for j in i + 1..route.len() - 1 {
+                let before = distances[route[i - 1]][route[i]].saturating_add(distances[route[j]][route[j + 1]]);
+                let after = distances[route[i - 1]][route[j]].saturating_add(distances[route[i]][route[j + 1]]);
+                if after < before {
+                    route[i..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+}
+
+pub fn collect_all_solve(maze: &Maze, waypoints: &[Point], budget: &SearchBudget) -> (Solution, Vec<Point>) {
+    let start_time = Instant::now();
+    let algorithm = if waypoints.len() <= EXACT_LIMIT { "Collect-All (DP)" } else { "Collect-All (2-opt)" };
+    let mut nodes_explored = 0;
+    
+    let mut stops = vec![maze.start];
+    stops.extend_from_slice(waypoints);
+    stops.push(maze.end);
+    
+    let mut trees = Vec::new();
+    for &stop in &stops {
+        match bfs_tree(maze, stop, budget, start_time, &mut nodes_explored) {
+            Ok(tree) => trees.push(tree),
+            Err(status) => return (solution(algorithm, Vec::new(), nodes_explored, start_time, status), Vec::new()),
+        }
+    }
+    
+    let distances: Vec<Vec<usize>> = trees.iter()
+        .map(|tree| stops.iter().map(|p| tree.distance.get(p).copied().unwrap_or(UNREACHABLE)).collect())
+        .collect();
+    if distances[0].contains(&UNREACHABLE) {
+        return (solution(algorithm, Vec::new(), nodes_explored, start_time, SearchStatus::NoPath), Vec::new());
+    }
+    
+    let route = if waypoints.len() <= EXACT_LIMIT {
+        held_karp(&distances)
+    } else {
+        let mut route = nearest_neighbor(&distances);
+        two_opt(&distances, &mut route);
+        Some(route)
+    };
+    let Some(route) = route.filter(|route| route_cost(&distances, route) != UNREACHABLE) else {
+        return (solution(algorithm, Vec::new(), nodes_explored, start_time, SearchStatus::NoPath), Vec::new());
+    };
+    
+    let mut path = vec![maze.start];
+    for leg in route.windows(2) {
+        let (from, to) = (leg[0], leg[1]);
+        path.extend(reconstruct_path(&trees[from].parent, stops[from], stops[to]).into_iter().skip(1));
+    }
+    
+    let order = route[1..route.len() - 1].iter().map(|&i| stops[i]).collect();
+    let mut found = solution(algorithm, path, nodes_explored, start_time, SearchStatus::Solved);
+    if waypoints.len() <= EXACT_LIMIT {
+        found.suboptimality_bound = Some(1.0);
+    }
+    (found, order)
+}
+
+fn random_cells(maze: &Maze, count: usize) -> (Vec<Point>, Vec<Point>) {
+    let mut cells = Vec::new();
+    for y in 0..maze.height {
+        for x in 0..maze.width {
+            let point = Point { x, y };
+            if maze.grid[y][x] == Cell::Path && point != maze.start && point != maze.end {
+                cells.push(point);
+            }
+        }
+    }
+    cells.shuffle(&mut rand::thread_rng());
+    let rest = cells.split_off(count.min(cells.len()));
+    (cells, rest)
+}
+
+fn print_row(solution: &Solution) {
+    println!("{:<20} | {:<12} | {:<6} | {:<15} | {:<12} | {:<10}",
+             solution.algorithm,
+             solution.path_length,
+             solution.turns,
+             solution.nodes_explored,
+             solution.time_taken,
+             solution.status.label());
+}
+
+pub fn run_goals() {
+    let (width, height) = crate::get_size_from_user();
+    let goal_count = crate::prompt_number("Number of goal cells to reach any one of (default: 3)", 3, |_: &usize| true);
+    let waypoint_count = crate::prompt_number("Number of waypoints to collect before the exit (default: 5)", 5, |_: &usize| true);
+    let show_visualizations = crate::get_display_choice();
+    let budget = crate::get_budget_from_user();
+    crate::install_cancel_handler(&budget);
+    
+    println!("\nGenerating random maze ({width}x{height})...");
+    let maze = Maze::new(width, height);
+    let (goals, rest) = random_cells(&maze, goal_count);
+    let mut waypoints = rest;
+    waypoints.truncate(waypoint_count);
+    
+    let nearest = nearest_goal_solve(&maze, &goals, &budget);
+    let (collect, order) = collect_all_solve(&maze, &waypoints, &budget);
+    
+    if show_visualizations {
+        let goal_marks: HashMap<Point, String> = goals.iter().map(|&p| (p, "GG".to_string())).collect();
+        println!("\n=== Nearest Goal ({} goals) ===", goals.len());
+        maze.display_with_marks(&nearest.path, &goal_marks);
+        
+        let waypoint_marks: HashMap<Point, String> = waypoints.iter()
+            .map(|&p| (p, format!("{:02}", order.iter().position(|&o| o == p).map_or(0, |i| (i + 1) % 100))))
+            .collect();
+        println!("\n=== Collect All ({} waypoints, numbered in visiting order) ===", waypoints.len());
+        maze.display_with_marks(&collect.path, &waypoint_marks);
+    }
+    
+    println!("\n=== SUMMARY ===");
+    println!("{:<20} | {:<12} | {:<6} | {:<15} | {:<12} | {:<10}",
+             "Task", "Path Length", "Turns", "Nodes Explored", "Time (μs)", "Status");
+    println!("{}", "-".repeat(92));
+    print_row(&nearest);
+    print_row(&collect);
+    println!("{}", "-".repeat(92));
+    
+    if let Some(goal) = nearest.path.last().filter(|_| nearest.status == SearchStatus::Solved) {
+        println!("Nearest goal: ({}, {}), {} steps from start.", goal.x, goal.y, nearest.path.len() - 1);
+    }
+    if collect.status == SearchStatus::Solved {
+        let stops: Vec<String> = order.iter().map(|p| format!("({}, {})", p.x, p.y)).collect();
+        println!("Waypoint order: {}", if stops.is_empty() { "none".to_string() } else { stops.join(" -> ") });
+        println!("Total route: {} steps from start to exit.", collect.path.len() - 1);
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Instant;
+use crate::algorithms::{reconstruct_path, SearchBudget};
+use crate::moves::to_run_length;
+use crate::{Maze, Point, SearchStatus, Solution};
+
+const ENUMERATE_LIMIT: usize = 10;
+
+fn restricted_bfs(maze: &Maze, from: Point, blocked_cells: &HashSet<Point>, blocked_edges: &HashSet<(Point, Point)>, nodes_explored: &mut usize) -> Option<Vec<Point>> {
+    let mut parent = HashMap::new();
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    visited.insert(from);
+    queue.push_back(from);
+    
+    while let Some(current) = queue.pop_front() {
+        *nodes_explored += 1;
+        if current == maze.end {
+            return Some(reconstruct_path(&parent, from, current));
+        }
+        for next in maze.get_neighbors(current) {
+            if blocked_cells.contains(&next) || blocked_edges.contains(&(current, next)) {
+                continue;
+            }
+            if visited.insert(next) {
+                parent.insert(next, current);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+pub fn yen_k_shortest(maze: &Maze, k: usize, budget: &SearchBudget) -> (Vec<Solution>, SearchStatus) {
+    let start_time = Instant::now();
+    let mut nodes_explored = 0;
+    let mut accepted: Vec<Vec<Point>> = Vec::new();
+    let mut candidates: Vec<Vec<Point>> = Vec::new();
+    let mut seen = HashSet::new();
+    let mut solutions = Vec::new();
+    
+    let accept = |path: &Vec<Point>, rank: usize, nodes_explored: usize| Solution::new(
+        format!("Yen #{}", rank),
+        path.clone(),
+        nodes_explored,
+        start_time.elapsed().as_micros(),
+        SearchStatus::Solved,
+    );
+    
+    let Some(first) = restricted_bfs(maze, maze.start, &HashSet::new(), &HashSet::new(), &mut nodes_explored) else {
+        return (solutions, SearchStatus::NoPath);
+    };
+    seen.insert(first.clone());
+    solutions.push(accept(&first, 1, nodes_explored));
+    accepted.push(first);
+    
+    while accepted.len() < k {
+        let previous = accepted.last().unwrap().clone();
+        for i in 0..previous.len() - 1 {
+            if let Some(status) = budget.check(nodes_explored, start_time) {
+                return (solutions, status);
+            }
+            
+            let root = &previous[..=i];
+            let blocked_edges: HashSet<(Point, Point)> = accepted.iter()
+                .filter(|path| path.len() > i + 1 && &path[..=i] == root)
+                .map(|path| (path[i], path[i + 1]))
+                .collect();
+            let blocked_cells: HashSet<Point> = root[..i].iter().copied().collect();
+            
+            if let Some(spur) = restricted_bfs(maze, previous[i], &blocked_cells, &blocked_edges, &mut nodes_explored) {
+                let path: Vec<Point> = root[..i].iter().copied().chain(spur).collect();
+                if seen.insert(path.clone()) {
+                    candidates.push(path);
+                }
+            }
+        }
+        
+        let Some(best) = (0..candidates.len()).min_by_key(|&i| candidates[i].len()) else {
+            break;
+        };
+        let path = candidates.swap_remove(best);
+        solutions.push(accept(&path, accepted.len() + 1, nodes_explored));
+        accepted.push(path);
+    }
+    
+    (solutions, SearchStatus::Solved)
+}
+
+pub struct ShortestDag {
+    distance: HashMap<Point, usize>,
+    from_start: HashMap<Point, u128>,
+    to_end: HashMap<Point, u128>,
+}
+
+impl ShortestDag {
+    pub fn new(maze: &Maze) -> Option<Self> {
+        let mut distance = HashMap::new();
+        let mut from_start = HashMap::new();
+        let mut order = Vec::new();
+        let mut queue = VecDeque::new();
+        distance.insert(maze.start, 0);
+        from_start.insert(maze.start, 1u128);
+        queue.push_back(maze.start);
+        
+        while let Some(current) = queue.pop_front() {
+            order.push(current);
+            for next in maze.get_neighbors(current) {
+                match distance.get(&next) {
+                    None => {
+                        distance.insert(next, distance[&current] + 1);
+                        from_start.insert(next, from_start[&current]);
+                        queue.push_back(next);
+                    }
+                    Some(&d) if d == distance[&current] + 1 => {
+                        let count = from_start[&next].saturating_add(from_start[&current]);
+                        from_start.insert(next, count);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        
+        if !distance.contains_key(&maze.end) {
+            return None;
+        }
+        
+        let mut to_end: HashMap<Point, u128> = HashMap::new();
+        for &current in order.iter().rev() {
+            let count = if current == maze.end {
+                1
+            } else {
+                maze.get_neighbors(current).into_iter()
+                    .filter(|next| distance[next] == distance[&current] + 1)
+                    .fold(0u128, |acc, next| acc.saturating_add(to_end.get(&next).copied().unwrap_or(0)))
+            };
+            to_end.insert(current, count);
+        }
+        
+        Some(ShortestDag { distance, from_start, to_end })
+    }
+    
+    pub fn length(&self, maze: &Maze) -> usize {
+        self.distance[&maze.end]
+    }
+    
+    pub fn count(&self, maze: &Maze) -> u128 {
+        self.from_start[&maze.end]
+    }
+    
+    pub fn essential_cells(&self, maze: &Maze) -> usize {
+        let total = self.count(maze);
+        self.to_end.iter()
+            .filter(|(p, &to_end)| to_end > 0 && self.from_start[p].saturating_mul(to_end) == total)
+            .count()
+    }
+    
+    pub fn enumerate(&self, maze: &Maze, limit: usize) -> Vec<Vec<Point>> {
+        let mut routes = Vec::new();
+        let mut stack = vec![vec![maze.start]];
+        while let Some(path) = stack.pop() {
+            if routes.len() >= limit {
+                break;
+            }
+            let current = *path.last().unwrap();
+            if current == maze.end {
+                routes.push(path);
+                continue;
+            }
+            for next in maze.get_neighbors(current).into_iter().rev() {
+                if self.distance[&next] == self.distance[&current] + 1 && self.to_end.get(&next).is_some_and(|&c| c > 0) {
+                    let mut extended = path.clone();
+                    extended.push(next);
+                    stack.push(extended);
+                }
+            }
+        }
+        routes
+    }
+}
+
+pub fn run_k_paths() {
+    let (mut maze, generated) = crate::load_or_generate_maze();
+    let loops = if generated {
+        let default = maze.width * maze.height / 20;
+        crate::prompt_number(&format!("Extra loops to knock open for a braid maze (default: {})", default), default, |_| true)
+    } else {
+        0
+    };
+    let k = crate::prompt_number("Number of shortest loopless routes to find, K (default: 5)", 5, |k: &usize| *k > 0);
+    let show_visualizations = crate::get_display_choice();
+    let budget = crate::get_budget_from_user();
+    crate::install_cancel_handler(&budget);
+    
+    maze.open_loops(loops, &mut rand::thread_rng());
+    let (solutions, status) = yen_k_shortest(&maze, k, &budget);
+    
+    if show_visualizations {
+        for solution in &solutions {
+            println!("\n=== {} ({} steps) ===", solution.algorithm, solution.path.len() - 1);
+            maze.display_with_path(&solution.path);
+        }
+    }
+    
+    println!("\n=== K-SHORTEST LOOPLESS ROUTES (K = {}) ===", k);
+    println!("{:<10} | {:<12} | {:<6} | {:<15} | {:<12} | Route",
+             "Rank", "Path Length", "Turns", "Nodes Explored", "Time (μs)");
+    println!("{}", "-".repeat(90));
+    for solution in &solutions {
+        println!("{:<10} | {:<12} | {:<6} | {:<15} | {:<12} | {}",
+                 solution.algorithm,
+                 solution.path_length,
+                 solution.turns,
+                 solution.nodes_explored,
+                 solution.time_taken,
+                 to_run_length(&solution.path));
+    }
+    println!("{}", "-".repeat(90));
+    if solutions.len() < k {
+        println!("Found {} of {} routes ({}).", solutions.len(), k, status.label());
+    }
+    
+    let Some(dag) = ShortestDag::new(&maze) else {
+        println!("No route from start to end.");
+        return;
+    };
+    let count = dag.count(&maze);
+    println!("\n=== ALL SHORTEST ROUTES ({} steps) ===", dag.length(&maze));
+    if count == u128::MAX {
+        println!("Count: more than {} (saturated)", u128::MAX);
+    } else {
+        println!("Count: {}", count);
+    }
+    println!("Cells shared by every shortest route: {}", dag.essential_cells(&maze));
+    for (i, route) in dag.enumerate(&maze, ENUMERATE_LIMIT).iter().enumerate() {
+        println!("  {:>2}. {}", i + 1, to_run_length(route));
+    }
+    if count > ENUMERATE_LIMIT as u128 {
+        println!("  ... (showing the first {})", ENUMERATE_LIMIT);
+    }
+}
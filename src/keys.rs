@@ -0,0 +1,218 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::time::Instant;
+use rand::Rng;
+use crate::algorithms::{bfs_solve, manhattan, reconstruct_states, SearchBudget};
+use crate::{Cell, Maze, Point, SearchStatus, Solution};
+
+const MAX_KEYS: usize = 26;
+
+type State = (Point, u32);
+
+fn passable(cell: Cell, keys: u32) -> bool {
+    match cell {
+        Cell::Wall => false,
+        Cell::Door(d) => keys & (1 << d) != 0,
+        _ => true,
+    }
+}
+
+fn successors(maze: &Maze, point: Point, keys: u32) -> Vec<State> {
+    let mut states = Vec::new();
+    let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+    
+    for (dx, dy) in directions.iter() {
+        let x = point.x as i32 + dx;
+        let y = point.y as i32 + dy;
+        
+        if x >= 0 && x < maze.width as i32 && y >= 0 && y < maze.height as i32 {
+            let neighbor = Point { x: x as usize, y: y as usize };
+            let cell = maze.grid[neighbor.y][neighbor.x];
+            if passable(cell, keys) {
+                let held = match cell {
+                    Cell::Key(k) => keys | (1 << k),
+                    _ => keys,
+                };
+                states.push((neighbor, held));
+            }
+        }
+    }
+    
+    states
+}
+
+fn reachable(maze: &Maze, open_doors: usize) -> HashSet<Point> {
+    let keys = (1u32 << open_doors) - 1;
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    seen.insert(maze.start);
+    queue.push_back(maze.start);
+    
+    while let Some(current) = queue.pop_front() {
+        for (neighbor, _) in successors(maze, current, keys) {
+            if seen.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    
+    seen
+}
+
+pub fn place_keys_and_doors(maze: &mut Maze, count: usize, rng: &mut impl Rng) -> usize {
+    let route = bfs_solve(maze, &SearchBudget::unlimited()).path;
+    let count = count.min(MAX_KEYS).min(route.len().saturating_sub(2) / 2);
+    let on_route: HashSet<Point> = route.iter().copied().collect();
+    
+    for i in 0..count {
+        let door = route[(i + 1) * (route.len() - 1) / (count + 1)];
+        maze.grid[door.y][door.x] = Cell::Door(i as u8);
+    }
+    
+    let mut before = HashSet::new();
+    for i in 0..count {
+        let region = reachable(maze, i);
+        let free = |p: &&Point| maze.grid[p.y][p.x] == Cell::Path && **p != maze.start && **p != maze.end;
+        let fresh: Vec<Point> = region.iter().filter(free).filter(|p| !before.contains(*p)).copied().collect();
+        let hidden: Vec<Point> = fresh.iter().filter(|p| !on_route.contains(*p)).copied().collect();
+        let candidates = if !hidden.is_empty() {
+            hidden
+        } else if !fresh.is_empty() {
+            fresh
+        } else {
+            region.iter().filter(free).copied().collect()
+        };
+        
+        let key = candidates[rng.gen_range(0..candidates.len())];
+        maze.grid[key.y][key.x] = Cell::Key(i as u8);
+        before = region;
+    }
+    
+    count
+}
+
+fn key_search(maze: &Maze, budget: &SearchBudget, algorithm: &str, use_heuristic: bool) -> Solution {
+    let start_time = Instant::now();
+    let heuristic = |p: Point| if use_heuristic { manhattan(p, maze.end) } else { 0 };
+    let start: State = (maze.start, 0);
+    let mut heap = BinaryHeap::new();
+    let mut cost: HashMap<State, usize> = HashMap::new();
+    let mut parent: HashMap<State, State> = HashMap::new();
+    let mut closed = HashSet::new();
+    let mut nodes_explored = 0;
+    let mut last = start;
+    
+    cost.insert(start, 0);
+    heap.push(Reverse((heuristic(maze.start), 0, maze.start.x, maze.start.y, 0u32)));
+    
+    let finish = |path: Vec<Point>, nodes_explored: usize, status: SearchStatus| Solution::new(
+        algorithm.to_string(),
+        path,
+        nodes_explored,
+        start_time.elapsed().as_micros(),
+        status,
+    ).with_bound(Some(1.0));
+    
+    while let Some(Reverse((_, g, x, y, keys))) = heap.pop() {
+        let state = (Point { x, y }, keys);
+        if !closed.insert(state) {
+            continue;
+        }
+        
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return finish(reconstruct_states(&parent, start, last), nodes_explored, status);
+        }
+        
+        nodes_explored += 1;
+        last = state;
+        
+        if state.0 == maze.end {
+            return finish(reconstruct_states(&parent, start, state), nodes_explored, SearchStatus::Solved);
+        }
+        
+        for next in successors(maze, state.0, keys) {
+            let next_cost = g + 1;
+            if cost.get(&next).is_none_or(|&c| next_cost < c) {
+                cost.insert(next, next_cost);
+                parent.insert(next, state);
+                heap.push(Reverse((next_cost + heuristic(next.0), next_cost, next.0.x, next.0.y, next.1)));
+            }
+        }
+    }
+    
+    finish(Vec::new(), nodes_explored, SearchStatus::NoPath)
+}
+
+pub fn key_bfs_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
+    key_search(maze, budget, "Key-State BFS", false)
+}
+
+pub fn key_astar_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
+    key_search(maze, budget, "Key-State A*", true)
+}
+
+pub fn key_order(maze: &Maze, path: &[Point]) -> Vec<u8> {
+    let mut order = Vec::new();
+    for p in path {
+        if let Cell::Key(k) = maze.grid[p.y][p.x] {
+            if !order.contains(&k) {
+                order.push(k);
+            }
+        }
+    }
+    order
+}
+
+pub fn run_keys() {
+    let (width, height) = crate::get_size_from_user();
+    let requested = crate::prompt_number(&format!("Number of key/door pairs (default: 3, maximum: {})", MAX_KEYS), 3, |n: &usize| *n <= MAX_KEYS);
+    let show_visualizations = crate::get_display_choice();
+    let budget = crate::get_budget_from_user();
+    crate::install_cancel_handler(&budget);
+    
+    println!("\nGenerating random maze ({width}x{height})...");
+    let mut maze = Maze::new(width, height);
+    let placed = place_keys_and_doors(&mut maze, requested, &mut rand::thread_rng());
+    if placed < requested {
+        println!("The route is too short for {} doors; placed {}.", requested, placed);
+    }
+    
+    println!("\n=== Maze (keys a-z open doors A-Z) ===");
+    maze.display_with_path(&[]);
+    
+    let mut solutions = vec![
+        key_bfs_solve(&maze, &budget),
+        key_astar_solve(&maze, &budget),
+        bfs_solve(&maze, &budget),
+    ];
+    solutions[2].algorithm = "BFS (no keys)".to_string();
+    
+    if show_visualizations {
+        for solution in solutions.iter().filter(|s| s.status == SearchStatus::Solved) {
+            println!("\n=== {} Solution ===", solution.algorithm);
+            maze.display_with_path(&solution.path);
+        }
+    }
+    
+    println!("\n=== SUMMARY ===");
+    println!("{:<16} | {:<12} | {:<6} | {:<15} | {:<12} | {:<10}",
+             "Algorithm", "Path Length", "Turns", "States Explored", "Time (μs)", "Status");
+    println!("{}", "-".repeat(88));
+    for solution in &solutions {
+        println!("{:<16} | {:<12} | {:<6} | {:<15} | {:<12} | {:<10}",
+                 solution.algorithm,
+                 solution.path_length,
+                 solution.turns,
+                 solution.nodes_explored,
+                 solution.time_taken,
+                 solution.status.label());
+    }
+    println!("{}", "-".repeat(88));
+    
+    for solution in solutions.iter().take(2).filter(|s| s.status == SearchStatus::Solved) {
+        let order: Vec<String> = key_order(&maze, &solution.path).iter()
+            .map(|&k| ((b'a' + k) as char).to_string())
+            .collect();
+        println!("{} key order: {}", solution.algorithm, if order.is_empty() { "none".to_string() } else { order.join(" -> ") });
+    }
+}
@@ -1,7 +1,25 @@
 mod algorithms;
+mod replanning;
+mod exploration;
+mod micromouse;
+mod moves;
+mod goals;
+mod keys;
+mod ascii;
+mod timed;
+mod theseus;
+mod multi_agent;
+mod mdp;
+mod k_paths;
+mod fields;
+mod analysis;
+mod placement;
+mod constrained;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io::{self, Write};
+use std::sync::atomic::Ordering;
+use std::time::Duration;
 use rand::Rng;
 use algorithms::*;
 
@@ -9,6 +27,11 @@ use algorithms::*;
 pub enum Cell {
     Wall,
     Path,
+    Key(u8),
+    Door(u8),
+    Teleport(u8),
+    OneWay(u8),
+    Periodic { period: u8, closed: u32 },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -17,12 +40,14 @@ pub struct Point {
     pub y: usize,
 }
 
+#[derive(Clone)]
 pub struct Maze {
     pub grid: Vec<Vec<Cell>>,
     pub width: usize,
     pub height: usize,
     pub start: Point,
     pub end: Point,
+    pub teleports: HashMap<Point, Point>,
 }
 
 impl Maze {
@@ -35,12 +60,48 @@ impl Maze {
             height,
             start: Point { x: 1, y: 1 },
             end: Point { x: width - 2, y: height - 2 },
+            teleports: HashMap::new(),
         };
         
         maze.generate();
+        debug_assert!({
+            let report = analysis::connectivity(&maze, &analysis::undirected_graph(&maze));
+            report.perfect && report.bridges == report.cells - 1
+        });
         maze
     }
     
+    fn from_grid(grid: Vec<Vec<Cell>>, start: Point, end: Point) -> Self {
+        let height = grid.len();
+        let width = grid.first().map_or(0, |row| row.len());
+        
+        let mut pads: HashMap<u8, Vec<Point>> = HashMap::new();
+        for (y, row) in grid.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if let Cell::Teleport(id) = cell {
+                    pads.entry(*id).or_default().push(Point { x, y });
+                }
+            }
+        }
+        
+        let mut teleports = HashMap::new();
+        for pair in pads.values() {
+            if let [a, b] = pair[..] {
+                teleports.insert(a, b);
+                teleports.insert(b, a);
+            }
+        }
+        
+        Maze {
+            grid,
+            width,
+            height,
+            start,
+            end,
+            teleports,
+        }
+    }
+    
     fn generate(&mut self) {
         let mut rng = rand::thread_rng();
         let mut stack = Vec::new();
@@ -72,6 +133,33 @@ impl Maze {
         self.grid[self.end.y][self.end.x] = Cell::Path;
     }
     
+    fn open_loops(&mut self, count: usize, rng: &mut impl Rng) {
+        let mut candidates = Vec::new();
+        for y in 1..self.height - 1 {
+            for x in 1..self.width - 1 {
+                let across = self.grid[y][x - 1] == Cell::Path && self.grid[y][x + 1] == Cell::Path;
+                let down = self.grid[y - 1][x] == Cell::Path && self.grid[y + 1][x] == Cell::Path;
+                if self.grid[y][x] == Cell::Wall && (across || down) {
+                    candidates.push(Point { x, y });
+                }
+            }
+        }
+        for _ in 0..count.min(candidates.len()) {
+            let wall = candidates.swap_remove(rng.gen_range(0..candidates.len()));
+            self.grid[wall.y][wall.x] = Cell::Path;
+        }
+    }
+    
+    fn toggle_cell(&mut self, point: Point) -> Option<Cell> {
+        let cell = &mut self.grid[point.y][point.x];
+        *cell = match *cell {
+            Cell::Wall => Cell::Path,
+            Cell::Path => Cell::Wall,
+            _ => return None,
+        };
+        Some(*cell)
+    }
+    
     fn get_unvisited_neighbors(&self, point: Point, visited: &HashSet<Point>) -> Vec<Point> {
         let mut neighbors = Vec::new();
         let directions = [(0, 2), (2, 0), (0, -2), (-2, 0)];
@@ -94,6 +182,7 @@ impl Maze {
     fn get_neighbors(&self, point: Point) -> Vec<Point> {
         let mut neighbors = Vec::new();
         let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+        let here = self.grid[point.y][point.x];
         
         for (dx, dy) in directions.iter() {
             let x = point.x as i32 + dx;
@@ -101,29 +190,86 @@ impl Maze {
             
             if x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32 {
                 let neighbor = Point { x: x as usize, y: y as usize };
-                if self.grid[neighbor.y][neighbor.x] == Cell::Path {
+                let heading = direction_between(point, neighbor) as u8;
+                let leaves = !matches!(here, Cell::OneWay(d) if d != heading);
+                let enters = match self.grid[neighbor.y][neighbor.x] {
+                    Cell::Path | Cell::Key(_) | Cell::Teleport(_) | Cell::Periodic { .. } => true,
+                    Cell::OneWay(d) => d == heading,
+                    Cell::Wall | Cell::Door(_) => false,
+                };
+                if leaves && enters {
                     neighbors.push(neighbor);
                 }
             }
         }
         
+        if let (Cell::Teleport(_), Some(&partner)) = (here, self.teleports.get(&point)) {
+            neighbors.push(partner);
+        }
+        
         neighbors
     }
     
+    fn get_predecessors(&self, point: Point) -> Vec<Point> {
+        let mut predecessors: Vec<Point> = [(0, 1), (1, 0), (0, -1), (-1, 0)].iter()
+            .filter_map(|(dx, dy)| {
+                let x = point.x as i32 + dx;
+                let y = point.y as i32 + dy;
+                (x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32)
+                    .then_some(Point { x: x as usize, y: y as usize })
+            })
+            .filter(|&q| self.grid[q.y][q.x] != Cell::Wall && self.get_neighbors(q).contains(&point))
+            .collect();
+        
+        if let Some(&partner) = self.teleports.get(&point) {
+            if self.get_neighbors(partner).contains(&point) {
+                predecessors.push(partner);
+            }
+        }
+        
+        predecessors
+    }
+    
+    fn get_adjacent(&self, point: Point) -> Vec<Point> {
+        let mut adjacent = self.get_neighbors(point);
+        for p in self.get_predecessors(point) {
+            if !adjacent.contains(&p) {
+                adjacent.push(p);
+            }
+        }
+        adjacent
+    }
+    
     fn display_with_path(&self, path: &[Point]) {
+        self.display_with_marks(path, &HashMap::new());
+    }
+    
+    fn display_with_marks(&self, path: &[Point], marks: &HashMap<Point, String>) {
+        self.display_with_tokens(path, marks, &HashMap::new());
+    }
+    
+    fn display_with_tokens(&self, path: &[Point], marks: &HashMap<Point, String>, tokens: &HashMap<Point, String>) {
         let path_set: HashSet<Point> = path.iter().cloned().collect();
         
         for (y, row) in self.grid.iter().enumerate() {
             for (x, cell) in row.iter().enumerate() {
                 let point = Point { x, y };
-                if point == self.start {
+                if let Some(token) = tokens.get(&point) {
+                    print!("{}", token);
+                } else if point == self.start {
                     print!("SS");
                 } else if point == self.end {
                     print!("EE");
-                } else if path_set.contains(&point) {
-                    print!("··");
+                } else if let Some(mark) = marks.get(&point) {
+                    print!("{}", mark);
                 } else {
                     match cell {
+                        Cell::Key(k) => print!("{0}{0}", (b'a' + k) as char),
+                        Cell::Door(d) => print!("{0}{0}", (b'A' + d) as char),
+                        Cell::Teleport(id) => print!("T{}", id),
+                        Cell::OneWay(d) => print!("{}", ["^^", ">>", "vv", "<<"][*d as usize]),
+                        Cell::Periodic { .. } => print!("░░"),
+                        _ if path_set.contains(&point) => print!("··"),
                         Cell::Wall => print!("██"),
                         Cell::Path => print!("  "),
                     }
@@ -134,24 +280,165 @@ impl Maze {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStatus {
+    Solved,
+    NoPath,
+    ExpansionLimit,
+    TimedOut,
+    Cancelled,
+}
+
+impl SearchStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SearchStatus::Solved => "Solved",
+            SearchStatus::NoPath => "No Path",
+            SearchStatus::ExpansionLimit => "Limit Hit",
+            SearchStatus::TimedOut => "Timed Out",
+            SearchStatus::Cancelled => "Cancelled",
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Solution {
     pub algorithm: String,
     pub path: Vec<Point>,
+    pub solution_cells: Vec<Point>,
     pub path_length: usize,
+    pub turns: usize,
+    pub walk_length: usize,
     pub nodes_explored: usize,
     pub time_taken: u128,
+    pub status: SearchStatus,
+    pub suboptimality_bound: Option<f64>,
+}
+
+impl Solution {
+    pub fn new(algorithm: String, path: Vec<Point>, nodes_explored: usize, time_taken: u128, status: SearchStatus) -> Self {
+        Solution {
+            algorithm,
+            path_length: path.len(),
+            turns: count_turns(&path),
+            walk_length: path.len(),
+            path,
+            solution_cells: Vec::new(),
+            nodes_explored,
+            time_taken,
+            status,
+            suboptimality_bound: None,
+        }
+    }
+    
+    pub fn with_bound(mut self, bound: Option<f64>) -> Self {
+        self.suboptimality_bound = bound;
+        self
+    }
+    
+    pub fn with_cells(mut self, cells: Vec<Point>) -> Self {
+        self.solution_cells = cells;
+        self
+    }
+}
+
+
+#[derive(Clone, Copy)]
+enum Algorithm {
+    Plain(Solver),
+    Walker(Solver),
+    Weighted(fn(&Maze, &SearchBudget, f64) -> Solution),
+    Anytime(fn(&Maze, &SearchBudget, f64) -> Vec<Solution>),
+    TurnAware(fn(&Maze, &SearchBudget, TurnCosts) -> Solution),
+    Learning(fn(&Maze, &SearchBudget, &LearningConfig) -> (Solution, Vec<usize>)),
+    Genetic(fn(&Maze, &SearchBudget, &GeneticConfig) -> (Solution, Vec<f64>)),
+    AntColony(fn(&Maze, &SearchBudget, &AntConfig) -> (Solution, Vec<f64>)),
+}
+
+struct AlgorithmOptions {
+    weight: f64,
+    turn_costs: TurnCosts,
+    learning: LearningConfig,
+    genetic: GeneticConfig,
+    ants: AntConfig,
 }
 
+impl Algorithm {
+    fn needs_weight(&self) -> bool {
+        matches!(self, Algorithm::Weighted(_) | Algorithm::Anytime(_))
+    }
+    
+    fn needs_turn_costs(&self) -> bool {
+        matches!(self, Algorithm::TurnAware(_))
+    }
+    
+    fn needs_learning(&self) -> bool {
+        matches!(self, Algorithm::Learning(_))
+    }
+    
+    fn needs_genetic(&self) -> bool {
+        matches!(self, Algorithm::Genetic(_))
+    }
+    
+    fn needs_ants(&self) -> bool {
+        matches!(self, Algorithm::AntColony(_))
+    }
+    
+    fn run(&self, maze: &Maze, budget: &SearchBudget, options: &AlgorithmOptions) -> Vec<Solution> {
+        match self {
+            Algorithm::Plain(solve) => vec![solve(maze, budget)],
+            Algorithm::Walker(solve) => {
+                let walk = solve(maze, budget);
+                let route = simplify_walk(maze, &walk.path);
+                let walk_length = walk.path.len();
+                vec![Solution {
+                    walk_length,
+                    ..Solution::new(walk.algorithm, route, walk.nodes_explored, walk.time_taken, walk.status)
+                }]
+            }
+            Algorithm::Weighted(solve) => vec![solve(maze, budget, options.weight)],
+            Algorithm::Anytime(solve) => solve(maze, budget, options.weight),
+            Algorithm::TurnAware(solve) => vec![solve(maze, budget, options.turn_costs)],
+            Algorithm::Learning(solve) => {
+                let (solution, curve) = solve(maze, budget, &options.learning);
+                let curve: Vec<f64> = curve.iter().map(|&steps| steps as f64).collect();
+                print_curve(&format!("{} learning curve (average steps per episode)", solution.algorithm), "Episodes", &curve);
+                vec![solution]
+            }
+            Algorithm::Genetic(solve) => {
+                let (solution, curve) = solve(maze, budget, &options.genetic);
+                print_curve(&format!("{} convergence over {} generations (best distance to goal)", solution.algorithm, curve.len()), "Generations", &curve);
+                vec![solution]
+            }
+            Algorithm::AntColony(solve) => {
+                let (solution, curve) = solve(maze, budget, &options.ants);
+                print_curve(&format!("{} convergence over {} iterations (best path length per iteration)", solution.algorithm, curve.len()), "Iterations", &curve);
+                vec![solution]
+            }
+        }
+    }
+}
 
-fn get_algorithm_choice() -> Vec<fn(&Maze) -> Solution> {
-    let available_algorithms = vec![
-        ("BFS", bfs_solve as fn(&Maze) -> Solution),
-        ("DFS", dfs_solve as fn(&Maze) -> Solution),
-        ("A*", astar_solve as fn(&Maze) -> Solution),
-        ("Dijkstra", dijkstra_solve as fn(&Maze) -> Solution),
-        ("Random", random_solve as fn(&Maze) -> Solution),
-        ("Wall-Following", custom_solve as fn(&Maze) -> Solution),
+fn get_algorithm_choice() -> Vec<Algorithm> {
+    let available_algorithms = [
+        ("BFS", Algorithm::Plain(bfs_solve)),
+        ("DFS", Algorithm::Plain(dfs_solve)),
+        ("A*", Algorithm::Plain(astar_solve)),
+        ("Dijkstra", Algorithm::Plain(dijkstra_solve)),
+        ("Random", Algorithm::Walker(random_solve)),
+        ("Wall-Following", Algorithm::Walker(custom_solve)),
+        ("Pledge", Algorithm::Walker(pledge_solve)),
+        ("Tremaux", Algorithm::Walker(tremaux_solve)),
+        ("Dead-End Fill", Algorithm::Plain(dead_end_fill_solve)),
+        ("Cul-de-sac Fill", Algorithm::Plain(cul_de_sac_fill_solve)),
+        ("Greedy Best-First", Algorithm::Plain(greedy_solve)),
+        ("Weighted A*", Algorithm::Weighted(weighted_astar_solve)),
+        ("ARA*", Algorithm::Anytime(ara_star_solve)),
+        ("D* Lite", Algorithm::Plain(dstar_lite_solve)),
+        ("Turn-Aware", Algorithm::TurnAware(turn_aware_solve)),
+        ("Q-Learning", Algorithm::Learning(q_learning_solve)),
+        ("Genetic", Algorithm::Genetic(genetic_solve)),
+        ("Ant Colony", Algorithm::AntColony(ant_colony_solve)),
     ];
     
     println!("\nAvailable algorithms:");
@@ -225,6 +512,166 @@ fn get_display_choice() -> bool {
     }
 }
 
+fn get_turn_costs_from_user() -> TurnCosts {
+    let mut input = String::new();
+    loop {
+        print!("\nTurn-aware costs as straight,turn,u-turn (default: 1,2,4): ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        if input.trim().is_empty() {
+            return TurnCosts::default();
+        }
+        let parts: Vec<Result<usize, _>> = input.trim().split(',').map(|p| p.trim().parse::<usize>()).collect();
+        match parts.as_slice() {
+            [Ok(straight), Ok(turn), Ok(u_turn)] if *straight > 0 => {
+                return TurnCosts { straight: *straight, turn: *turn, u_turn: *u_turn };
+            }
+            _ => println!("Invalid input. Please enter three numbers like 1,2,4 (straight cost at least 1)."),
+        }
+    }
+}
+
+fn get_learning_config_from_user() -> LearningConfig {
+    let mut input = String::new();
+    let defaults = LearningConfig::default();
+    loop {
+        print!("\nQ-learning episodes,seed (default: {},{}): ", defaults.episodes, defaults.seed);
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        if input.trim().is_empty() {
+            return defaults;
+        }
+        let parts: Vec<&str> = input.trim().split(',').map(|p| p.trim()).collect();
+        match (parts[0].parse::<usize>(), parts.get(1).map(|s| s.parse::<u64>())) {
+            (Ok(episodes), None) if episodes > 0 => return LearningConfig { episodes, ..defaults },
+            (Ok(episodes), Some(Ok(seed))) if episodes > 0 => return LearningConfig { episodes, seed, ..defaults },
+            _ => println!("Invalid input. Please enter episodes and an optional seed like 500,42."),
+        }
+    }
+}
+
+fn get_genetic_config_from_user() -> GeneticConfig {
+    let mut input = String::new();
+    let defaults = GeneticConfig::default();
+    loop {
+        print!("\nGenetic population,generations,mutation rate,seed (default: {},{},{},{}): ",
+               defaults.population, defaults.generations, defaults.mutation_rate, defaults.seed);
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        if input.trim().is_empty() {
+            return defaults;
+        }
+        let parts: Vec<&str> = input.trim().split(',').map(|p| p.trim()).collect();
+        match parts.as_slice() {
+            [population, generations, rate, seed] => {
+                match (population.parse::<usize>(), generations.parse::<usize>(), rate.parse::<f64>(), seed.parse::<u64>()) {
+                    (Ok(population), Ok(generations), Ok(mutation_rate), Ok(seed)) if population > 0 && (0.0..=1.0).contains(&mutation_rate) => {
+                        return GeneticConfig { population, generations, mutation_rate, seed };
+                    }
+                    _ => println!("Invalid input. Population must be positive and the mutation rate between 0 and 1."),
+                }
+            }
+            _ => println!("Invalid input. Please enter four values like 100,200,0.02,42."),
+        }
+    }
+}
+
+fn get_ant_config_from_user() -> AntConfig {
+    let mut input = String::new();
+    let defaults = AntConfig::default();
+    loop {
+        print!("\nAnt colony ants,iterations,evaporation,alpha,beta,seed (default: {},{},{},{},{},{}): ",
+               defaults.ants, defaults.iterations, defaults.evaporation, defaults.alpha, defaults.beta, defaults.seed);
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        if input.trim().is_empty() {
+            return defaults;
+        }
+        let parts: Vec<&str> = input.trim().split(',').map(|p| p.trim()).collect();
+        match parts.as_slice() {
+            [ants, iterations, evaporation, alpha, beta, seed] => {
+                match (ants.parse::<usize>(), iterations.parse::<usize>(), evaporation.parse::<f64>(), alpha.parse::<f64>(), beta.parse::<f64>(), seed.parse::<u64>()) {
+                    (Ok(ants), Ok(iterations), Ok(evaporation), Ok(alpha), Ok(beta), Ok(seed)) if ants > 0 && (0.0..1.0).contains(&evaporation) => {
+                        return AntConfig { ants, iterations, evaporation, alpha, beta, seed };
+                    }
+                    _ => println!("Invalid input. Ants must be positive and evaporation at least 0 and below 1."),
+                }
+            }
+            _ => println!("Invalid input. Please enter six values like 20,50,0.1,1,2,42."),
+        }
+    }
+}
+
+fn print_curve(title: &str, unit: &str, curve: &[f64]) {
+    if curve.is_empty() {
+        return;
+    }
+    let bucket = curve.len().div_ceil(10);
+    println!("{}:", title);
+    for (i, chunk) in curve.chunks(bucket).enumerate() {
+        let finite: Vec<f64> = chunk.iter().copied().filter(|v| v.is_finite()).collect();
+        let average = if finite.is_empty() {
+            "-".to_string()
+        } else {
+            format!("{:.1}", finite.iter().sum::<f64>() / finite.len() as f64)
+        };
+        println!("  {} {:>5}-{:<5} {:>10}", unit, i * bucket + 1, i * bucket + chunk.len(), average);
+    }
+}
+
+fn get_budget_from_user() -> SearchBudget {
+    let mut input = String::new();
+    let mut budget = SearchBudget::unlimited();
+    
+    loop {
+        print!("\nMax node expansions per algorithm (default: unlimited): ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        if input.trim().is_empty() {
+            break;
+        }
+        match input.trim().parse::<usize>() {
+            Ok(n) if n > 0 => {
+                budget.max_expansions = Some(n);
+                break;
+            }
+            _ => println!("Invalid input. Please enter a positive number or press Enter for default."),
+        }
+    }
+    
+    loop {
+        print!("Time limit per algorithm in ms (default: 10000, 0 for none): ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        if input.trim().is_empty() {
+            budget.max_time = Some(Duration::from_millis(10_000));
+            break;
+        }
+        match input.trim().parse::<u64>() {
+            Ok(0) => break,
+            Ok(ms) => {
+                budget.max_time = Some(Duration::from_millis(ms));
+                break;
+            }
+            Err(_) => println!("Invalid input. Please enter a number or press Enter for default."),
+        }
+    }
+    
+    budget
+}
+
 fn get_size_from_user() -> (usize, usize) {
     let mut input = String::new();
     
@@ -275,64 +722,290 @@ fn get_size_from_user() -> (usize, usize) {
     }
 }
 
-fn main() {
-    let (width, height) = get_size_from_user();
+fn prompt_number<T: std::str::FromStr>(prompt: &str, default: T, valid: impl Fn(&T) -> bool) -> T {
+    let mut input = String::new();
+    loop {
+        print!("\n{}: ", prompt);
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        if input.trim().is_empty() {
+            return default;
+        }
+        match input.trim().parse::<T>() {
+            Ok(value) if valid(&value) => return value,
+            _ => println!("Invalid input. Please try again or press Enter for default."),
+        }
+    }
+}
+
+fn get_maze_from_user() -> Maze {
+    load_or_generate_maze().0
+}
+
+fn load_or_generate_maze() -> (Maze, bool) {
+    let mut input = String::new();
+    loop {
+        print!("\nPath to a maze file (.maz or ASCII), or Enter for a random maze: ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        let path = input.trim();
+        if path.is_empty() {
+            let (width, height) = get_size_from_user();
+            let placement = placement::get_placement_from_user(width, height);
+            println!("\nGenerating random maze ({width}x{height})...");
+            let mut maze = Maze::new(width, height);
+            let placement = if placement::place_endpoints(&mut maze, placement, &mut rand::thread_rng()) {
+                placement
+            } else {
+                println!("Could not satisfy the placement; keeping the corners.");
+                placement::Placement::Corners
+            };
+            println!("{}", placement::describe(&maze, placement));
+            return (maze, true);
+        }
+        match ascii::load_maze_file(path) {
+            Ok(maze) => return (maze, false),
+            Err(e) => println!("Error: {}", e),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Compare,
+    Replanning,
+    Exploration,
+    Micromouse,
+    Routes,
+    Goals,
+    Keys,
+    Timed,
+    Theseus,
+    MultiAgent,
+    Mdp,
+    KPaths,
+    Fields,
+    Constrained,
+}
+
+fn get_mode_choice() -> Mode {
+    let modes = [
+        ("Compare algorithms on a random maze", Mode::Compare),
+        ("Dynamic replanning with D* Lite", Mode::Replanning),
+        ("Explore with limited sensors", Mode::Exploration),
+        ("Micromouse runs on a .maz file", Mode::Micromouse),
+        ("Route strings: encode and check move instructions", Mode::Routes),
+        ("Multiple goals and collect-all waypoints", Mode::Goals),
+        ("Keys and doors puzzle", Mode::Keys),
+        ("Time-dependent maze with gates and patrols", Mode::Timed),
+        ("Theseus and the Minotaur", Mode::Theseus),
+        ("Multi-agent routing with Conflict-Based Search", Mode::MultiAgent),
+        ("Slippery moves: MDP value and policy iteration", Mode::Mdp),
+        ("K-shortest routes and shortest-route counting", Mode::KPaths),
+        ("Distance heatmap and flow field", Mode::Fields),
+        ("Generate mazes to a target solution length or difficulty", Mode::Constrained),
+    ];
+    
+    println!("Modes:");
+    for (i, (name, _)) in modes.iter().enumerate() {
+        println!("  {}. {}", i + 1, name);
+    }
+    
+    let mut input = String::new();
+    loop {
+        print!("\nSelect mode (default: 1): ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        if input.trim().is_empty() {
+            return modes[0].1;
+        }
+        match input.trim().parse::<usize>() {
+            Ok(num) if num >= 1 && num <= modes.len() => return modes[num - 1].1,
+            _ => println!("Invalid input. Please enter a number between 1 and {}.", modes.len()),
+        }
+    }
+}
+
+fn install_cancel_handler(budget: &SearchBudget) {
+    let cancel = budget.cancel.clone();
+    ctrlc::set_handler(move || {
+        if cancel.swap(true, Ordering::SeqCst) {
+            std::process::exit(130);
+        }
+    }).expect("failed to install Ctrl-C handler");
+}
+
+fn run_comparison() {
+    let maze = get_maze_from_user();
     
     let selected_algorithms = get_algorithm_choice();
     
+    let options = AlgorithmOptions {
+        weight: if selected_algorithms.iter().any(|a| a.needs_weight()) {
+            prompt_number("Heuristic weight for Weighted A* / initial weight for ARA* (default: 2.0, minimum: 1.0)", 2.0, |w: &f64| *w >= 1.0)
+        } else {
+            1.0
+        },
+        turn_costs: if selected_algorithms.iter().any(|a| a.needs_turn_costs()) {
+            get_turn_costs_from_user()
+        } else {
+            TurnCosts::default()
+        },
+        learning: if selected_algorithms.iter().any(|a| a.needs_learning()) {
+            get_learning_config_from_user()
+        } else {
+            LearningConfig::default()
+        },
+        genetic: if selected_algorithms.iter().any(|a| a.needs_genetic()) {
+            get_genetic_config_from_user()
+        } else {
+            GeneticConfig::default()
+        },
+        ants: if selected_algorithms.iter().any(|a| a.needs_ants()) {
+            get_ant_config_from_user()
+        } else {
+            AntConfig::default()
+        },
+    };
+    
     let show_visualizations = get_display_choice();
     
-    println!("\nGenerating random maze ({width}x{height})...");
-    let maze = Maze::new(width, height);
+    let budget = get_budget_from_user();
     
-    println!("Solving with selected algorithms...\n");
+    install_cancel_handler(&budget);
     
-    let mut solutions: Vec<Solution> = selected_algorithms.iter()
-        .map(|algo| algo(&maze))
-        .collect();
+    println!("\nSolving with selected algorithms (Ctrl-C to stop early)...\n");
     
-    solutions.iter_mut().for_each(|s| {
-        s.path_length = s.path.len();
-    });
+    let solutions: Vec<Solution> = selected_algorithms.iter()
+        .flat_map(|algo| algo.run(&maze, &budget, &options))
+        .collect();
     
     if show_visualizations {
         for solution in &solutions {
-            println!("\n=== {} Solution ===", solution.algorithm);
-            maze.display_with_path(&solution.path);
+            if solution.status == SearchStatus::Solved {
+                println!("\n=== {} Solution ===", solution.algorithm);
+            } else {
+                println!("\n=== {} Partial Result ({}) ===", solution.algorithm, solution.status.label());
+            }
+            let marks: HashMap<Point, String> = solution.solution_cells.iter()
+                .filter(|p| !solution.path.contains(p))
+                .map(|&p| (p, "::".to_string()))
+                .collect();
+            maze.display_with_marks(&solution.path, &marks);
+            if !solution.solution_cells.is_empty() {
+                println!("Cells left open by filling: {} (off-route ones shown as ::)", solution.solution_cells.len());
+            }
+            if solution.status == SearchStatus::Solved && solution.path.len() > 1 {
+                println!("Route: {}", moves::to_run_length(&solution.path));
+            }
             println!();
         }
     }
     
     println!("\n=== SUMMARY ===");
-    println!("{:<16} | {:<12} | {:<15} | {:<12}", 
-             "Algorithm", "Path Length", "Nodes Explored", "Time (μs)");
-    println!("{}", "-".repeat(70));
+    println!("{:<16} | {:<12} | {:<12} | {:<6} | {:<15} | {:<12} | {:<10}", 
+             "Algorithm", "Path Length", "Walk Length", "Turns", "Nodes Explored", "Time (μs)", "Status");
+    println!("{}", "-".repeat(107));
     
     for solution in &solutions {
-        println!("{:<16} | {:<12} | {:<15} | {:<12}",
+        println!("{:<16} | {:<12} | {:<12} | {:<6} | {:<15} | {:<12} | {:<10}",
                  solution.algorithm,
                  solution.path_length,
+                 solution.walk_length,
+                 solution.turns,
                  solution.nodes_explored,
-                 solution.time_taken);
+                 solution.time_taken,
+                 solution.status.label());
     }
     
-    let best_path = solutions.iter()
-        .min_by_key(|s| s.path_length)
-        .unwrap();
+    let solved: Vec<&Solution> = solutions.iter()
+        .filter(|s| s.status == SearchStatus::Solved)
+        .collect();
     
-    let fastest = solutions.iter()
-        .min_by_key(|s| s.time_taken)
-        .unwrap();
+    println!("\n{}", "-".repeat(107));
+    if solved.is_empty() {
+        println!("No algorithm reached the goal within the budget.");
+    } else {
+        let best_path = solved.iter()
+            .min_by_key(|s| s.path_length)
+            .unwrap();
+        
+        let fastest = solved.iter()
+            .min_by_key(|s| s.time_taken)
+            .unwrap();
+        
+        let most_efficient = solved.iter()
+            .min_by_key(|s| s.nodes_explored)
+            .unwrap();
+        
+        println!("Best Path Length: {} ({} steps)", best_path.algorithm, best_path.path_length);
+        println!("Fastest: {} ({} μs)", fastest.algorithm, fastest.time_taken);
+        println!("Most Efficient: {} ({} nodes explored)", most_efficient.algorithm, most_efficient.nodes_explored);
+    }
     
-    let most_efficient = solutions.iter()
-        .min_by_key(|s| s.nodes_explored)
-        .unwrap();
+    let unfinished = solutions.len() - solved.len();
+    if unfinished > 0 {
+        println!("{} algorithm(s) stopped early; their path lengths are partial.", unfinished);
+    }
+    println!("{}", "-".repeat(107));
+    
+    let graph = analysis::undirected_graph(&maze);
+    analysis::print_report(&maze, &analysis::analyze(&maze, &graph));
+    analysis::print_connectivity(&analysis::connectivity(&maze, &graph));
+    
+    if !solved.is_empty() {
+        let optimal = solved.iter()
+            .find(|s| s.algorithm == "A*")
+            .map(|s| s.path_length)
+            .or_else(|| {
+                let reference = astar_solve(&maze, &SearchBudget::unlimited());
+                (reference.status == SearchStatus::Solved).then_some(reference.path.len())
+            });
+        let Some(optimal) = optimal else {
+            return;
+        };
+        
+        println!("\n=== SUBOPTIMALITY vs A* (optimal: {} steps) ===", optimal);
+        println!("{:<16} | {:<12} | {:<12}", "Algorithm", "Bound", "Achieved");
+        println!("{}", "-".repeat(46));
+        for solution in &solved {
+            let bound = match solution.suboptimality_bound {
+                Some(b) => format!("{:.3}", b),
+                None => "none".to_string(),
+            };
+            println!("{:<16} | {:<12} | {:<12.3}",
+                     solution.algorithm,
+                     bound,
+                     solution.path_length as f64 / optimal.max(1) as f64);
+        }
+    }
+}
+
+fn main() {
+    let mode = get_mode_choice();
     
-    println!("\n{}", "-".repeat(70));
-    println!("Best Path Length: {} ({} steps)", best_path.algorithm, best_path.path_length);
-    println!("Fastest: {} ({} μs)", fastest.algorithm, fastest.time_taken);
-    println!("Most Efficient: {} ({} nodes explored)", most_efficient.algorithm, most_efficient.nodes_explored);
-    println!("{}", "-".repeat(70));
+    match mode {
+        Mode::Compare => run_comparison(),
+        Mode::Replanning => replanning::run_replanning(),
+        Mode::Exploration => exploration::run_exploration(),
+        Mode::Micromouse => micromouse::run_micromouse(),
+        Mode::Routes => moves::run_routes(),
+        Mode::Goals => goals::run_goals(),
+        Mode::Keys => keys::run_keys(),
+        Mode::Timed => timed::run_timed(),
+        Mode::Theseus => theseus::run_theseus(),
+        Mode::MultiAgent => multi_agent::run_multi_agent(),
+        Mode::Mdp => mdp::run_mdp(),
+        Mode::KPaths => k_paths::run_k_paths(),
+        Mode::Fields => fields::run_fields(),
+        Mode::Constrained => constrained::run_constrained(),
+    }
 
     println!("\nPress Enter to exit...");
     let mut exit_input = String::new();
@@ -0,0 +1,328 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Instant;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use crate::algorithms::custom::{get_neighbor_in_direction, is_valid_path};
+use crate::algorithms::direction_between;
+use crate::{Cell, Maze, Point};
+
+const EPSILON: f64 = 1e-6;
+const MAX_SWEEPS: usize = 100_000;
+const ARROWS: [&str; 4] = ["↑ ", "→ ", "↓ ", "← "];
+
+pub struct Mdp<'a> {
+    maze: &'a Maze,
+    slip: f64,
+    hazard_penalty: f64,
+    hazards: HashSet<Point>,
+    states: Vec<Point>,
+    index: HashMap<Point, usize>,
+    reaches_goal: Vec<bool>,
+}
+
+pub struct PolicyResult {
+    pub values: Vec<f64>,
+    pub policy: Vec<usize>,
+    pub iterations: usize,
+    pub converged: bool,
+    pub time_taken: u128,
+}
+
+pub struct Simulation {
+    pub reached: usize,
+    pub mean_steps: f64,
+    pub mean_return: f64,
+    pub hazard_hits: f64,
+}
+
+impl<'a> Mdp<'a> {
+    pub fn new(maze: &'a Maze, slip: f64, hazard_penalty: f64, hazards: HashSet<Point>) -> Self {
+        let states: Vec<Point> = (0..maze.height)
+            .flat_map(|y| (0..maze.width).map(move |x| Point { x, y }))
+            .filter(|p| maze.grid[p.y][p.x] != Cell::Wall)
+            .collect();
+        let index = states.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+        let mut mdp = Mdp { maze, slip, hazard_penalty, hazards, states, index, reaches_goal: Vec::new() };
+        mdp.reaches_goal = mdp.goal_reachability();
+        mdp
+    }
+    
+    fn goal_reachability(&self) -> Vec<bool> {
+        let mut reaches = vec![false; self.states.len()];
+        let mut queue = VecDeque::new();
+        reaches[self.index[&self.maze.end]] = true;
+        queue.push_back(self.maze.end);
+        while let Some(current) = queue.pop_front() {
+            for direction in 0..4 {
+                let Some(&i) = get_neighbor_in_direction(current, direction).and_then(|p| self.index.get(&p)) else {
+                    continue;
+                };
+                if !reaches[i] && self.move_toward(self.states[i], (direction + 2) % 4) == current {
+                    reaches[i] = true;
+                    queue.push_back(self.states[i]);
+                }
+            }
+        }
+        reaches
+    }
+    
+    fn initial_values(&self, unreachable: f64) -> Vec<f64> {
+        self.reaches_goal.iter().map(|&r| if r { 0.0 } else { unreachable }).collect()
+    }
+    
+    fn move_toward(&self, from: Point, direction: usize) -> Point {
+        get_neighbor_in_direction(from, direction)
+            .filter(|&p| is_valid_path(self.maze, from, p))
+            .unwrap_or(from)
+    }
+    
+    fn outcomes(&self, from: Point, action: usize) -> [(Point, f64); 3] {
+        [
+            (self.move_toward(from, action), 1.0 - self.slip),
+            (self.move_toward(from, (action + 1) % 4), self.slip / 2.0),
+            (self.move_toward(from, (action + 3) % 4), self.slip / 2.0),
+        ]
+    }
+    
+    fn reward(&self, to: Point) -> f64 {
+        if self.hazards.contains(&to) { -1.0 - self.hazard_penalty } else { -1.0 }
+    }
+    
+    fn expectation(&self, values: &[f64], from: Point, action: usize, step_reward: impl Fn(Point) -> f64) -> f64 {
+        self.outcomes(from, action).iter()
+            .filter(|&&(_, p)| p > 0.0)
+            .map(|&(to, p)| p * (step_reward(to) + values[self.index[&to]]))
+            .sum()
+    }
+    
+    fn q_value(&self, values: &[f64], from: Point, action: usize) -> f64 {
+        self.expectation(values, from, action, |to| self.reward(to))
+    }
+    
+    fn greedy(&self, values: &[f64], from: Point) -> usize {
+        (0..4).max_by(|&a, &b| self.q_value(values, from, a).total_cmp(&self.q_value(values, from, b))).unwrap()
+    }
+    
+    pub fn value_iteration(&self) -> PolicyResult {
+        let start_time = Instant::now();
+        let mut values = self.initial_values(f64::NEG_INFINITY);
+        let mut iterations = 0;
+        let mut converged = false;
+        
+        while !converged && iterations < MAX_SWEEPS {
+            iterations += 1;
+            let mut delta: f64 = 0.0;
+            for (i, &s) in self.states.iter().enumerate() {
+                if s == self.maze.end || !self.reaches_goal[i] {
+                    continue;
+                }
+                let best = (0..4).map(|a| self.q_value(&values, s, a)).fold(f64::NEG_INFINITY, f64::max);
+                delta = delta.max((best - values[i]).abs());
+                values[i] = best;
+            }
+            converged = delta < EPSILON;
+        }
+        
+        let policy = self.states.iter().map(|&s| self.greedy(&values, s)).collect();
+        PolicyResult { values, policy, iterations, converged, time_taken: start_time.elapsed().as_micros() }
+    }
+    
+    fn evaluate(&self, policy: &[usize], step_reward: impl Fn(Point) -> f64, unreachable: f64) -> (Vec<f64>, bool) {
+        let mut values = self.initial_values(unreachable);
+        for _ in 0..MAX_SWEEPS {
+            let mut delta: f64 = 0.0;
+            for (i, &s) in self.states.iter().enumerate() {
+                if s == self.maze.end || !self.reaches_goal[i] {
+                    continue;
+                }
+                let value = self.expectation(&values, s, policy[i], &step_reward);
+                delta = delta.max((value - values[i]).abs());
+                values[i] = value;
+            }
+            if delta < EPSILON {
+                return (values, true);
+            }
+        }
+        (values, false)
+    }
+    
+    fn shortest_path_policy(&self) -> Vec<usize> {
+        let mut distance = HashMap::new();
+        let mut queue = VecDeque::new();
+        distance.insert(self.maze.end, 0);
+        queue.push_back(self.maze.end);
+        while let Some(current) = queue.pop_front() {
+            for neighbor in self.maze.get_neighbors(current) {
+                if !distance.contains_key(&neighbor) {
+                    distance.insert(neighbor, distance[&current] + 1);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        
+        self.states.iter()
+            .map(|&s| {
+                self.maze.get_neighbors(s).into_iter()
+                    .filter(|n| distance.contains_key(n))
+                    .min_by_key(|n| distance[n])
+                    .map_or(0, |n| direction_between(s, n))
+            })
+            .collect()
+    }
+    
+    pub fn policy_iteration(&self) -> PolicyResult {
+        let start_time = Instant::now();
+        let mut policy = self.shortest_path_policy();
+        let mut iterations = 0;
+        
+        loop {
+            iterations += 1;
+            let (values, evaluated) = self.evaluate(&policy, |to| self.reward(to), f64::NEG_INFINITY);
+            let mut stable = true;
+            for (i, &s) in self.states.iter().enumerate() {
+                let best = self.greedy(&values, s);
+                if self.q_value(&values, s, best) > self.q_value(&values, s, policy[i]) + EPSILON {
+                    policy[i] = best;
+                    stable = false;
+                }
+            }
+            if stable || iterations >= MAX_SWEEPS {
+                let converged = stable && evaluated;
+                return PolicyResult { values, policy, iterations, converged, time_taken: start_time.elapsed().as_micros() };
+            }
+        }
+    }
+    
+    pub fn expected_steps(&self, policy: &[usize]) -> Option<f64> {
+        let (values, converged) = self.evaluate(policy, |_| 1.0, f64::INFINITY);
+        converged.then(|| values[self.index[&self.maze.start]])
+    }
+    
+    pub fn value_at_start(&self, result: &PolicyResult) -> f64 {
+        result.values[self.index[&self.maze.start]]
+    }
+    
+    pub fn simulate(&self, policy: &[usize], episodes: usize, rng: &mut impl Rng) -> Simulation {
+        let step_limit = 100 * self.states.len();
+        let mut reached = 0;
+        let mut total_steps = 0;
+        let mut total_return = 0.0;
+        let mut hazard_hits = 0;
+        
+        for _ in 0..episodes {
+            let mut current = self.maze.start;
+            let mut steps = 0;
+            while current != self.maze.end && steps < step_limit {
+                let action = policy[self.index[&current]];
+                let outcomes = self.outcomes(current, action);
+                let next = outcomes.choose_weighted(rng, |o| o.1).unwrap().0;
+                total_return += self.reward(next);
+                if self.hazards.contains(&next) {
+                    hazard_hits += 1;
+                }
+                current = next;
+                steps += 1;
+            }
+            if current == self.maze.end {
+                reached += 1;
+            }
+            total_steps += steps;
+        }
+        
+        let n = episodes.max(1) as f64;
+        Simulation {
+            reached,
+            mean_steps: total_steps as f64 / n,
+            mean_return: total_return / n,
+            hazard_hits: hazard_hits as f64 / n,
+        }
+    }
+    
+    pub fn display_policy(&self, policy: &[usize]) {
+        let mut marks = HashMap::new();
+        for (i, &s) in self.states.iter().enumerate() {
+            if s == self.maze.start || s == self.maze.end {
+                continue;
+            }
+            let mark = if self.hazards.contains(&s) { "!!" } else { ARROWS[policy[i]] };
+            marks.insert(s, mark.to_string());
+        }
+        self.maze.display_with_marks(&[], &marks);
+    }
+}
+
+fn random_hazards(maze: &Maze, count: usize, rng: &mut impl Rng) -> HashSet<Point> {
+    let mut cells: Vec<Point> = (0..maze.height)
+        .flat_map(|y| (0..maze.width).map(move |x| Point { x, y }))
+        .filter(|&p| maze.grid[p.y][p.x] == Cell::Path && p != maze.start && p != maze.end)
+        .collect();
+    cells.shuffle(rng);
+    cells.into_iter().take(count).collect()
+}
+
+pub fn run_mdp() {
+    let (width, height) = crate::get_size_from_user();
+    let slip = crate::prompt_number("Slip probability to a perpendicular direction (default: 0.2, 0 to 0.9)", 0.2, |p: &f64| (0.0..=0.9).contains(p));
+    let hazard_count = crate::prompt_number("Number of hazard cells (default: 10)", 10, |_: &usize| true);
+    let hazard_penalty = crate::prompt_number("Hazard penalty per visit (default: 10)", 10.0, |p: &f64| *p >= 0.0);
+    let episodes = crate::prompt_number("Episodes to simulate (default: 1000)", 1000, |n: &usize| *n > 0);
+    let show_visualizations = crate::get_display_choice();
+    
+    let mut rng = rand::thread_rng();
+    println!("\nGenerating random maze ({width}x{height}) with extra loops...");
+    let mut maze = Maze::new(width, height);
+    maze.open_loops(width * height / 30, &mut rng);
+    let hazards = random_hazards(&maze, hazard_count, &mut rng);
+    let mdp = Mdp::new(&maze, slip, hazard_penalty, hazards);
+    
+    let results = [("Value Iteration", mdp.value_iteration()), ("Policy Iteration", mdp.policy_iteration())];
+    
+    if show_visualizations {
+        for (name, result) in &results {
+            println!("\n=== {} Policy (!! hazard) ===", name);
+            mdp.display_policy(&result.policy);
+        }
+    }
+    
+    println!("\n=== MDP SUMMARY (slip {:.2}, {} hazards, penalty {}) ===", slip, mdp.hazards.len(), hazard_penalty);
+    println!("{:<16} | {:<10} | {:<12} | {:<14} | {:<15}",
+             "Solver", "Iterations", "Time (μs)", "Expected Steps", "Expected Return");
+    println!("{}", "-".repeat(78));
+    for (name, result) in &results {
+        let expected_steps = mdp.expected_steps(&result.policy)
+            .map_or("no convergence".to_string(), |steps| format!("{:.2}", steps));
+        let expected_return = if result.converged {
+            format!("{:.2}", mdp.value_at_start(result))
+        } else {
+            "no convergence".to_string()
+        };
+        println!("{:<16} | {:<10} | {:<12} | {:<14} | {:<15}",
+                 name,
+                 result.iterations,
+                 result.time_taken,
+                 expected_steps,
+                 expected_return);
+    }
+    println!("{}", "-".repeat(78));
+    for (name, result) in results.iter().filter(|(_, result)| !result.converged) {
+        println!("{} stopped after {} sweeps without converging; its policy may be improper.", name, result.iterations);
+    }
+    
+    let disagreements = results[0].1.policy.iter().zip(&results[1].1.policy).filter(|(a, b)| a != b).count();
+    println!("Policies differ in {} of {} cells (ties can pick different arrows).", disagreements, mdp.states.len());
+    
+    println!("\n=== SIMULATION ({} episodes per policy) ===", episodes);
+    println!("{:<16} | {:<10} | {:<12} | {:<12} | {:<12}",
+             "Policy", "Reached", "Mean Steps", "Mean Return", "Hazard Hits");
+    println!("{}", "-".repeat(72));
+    for (name, result) in &results {
+        let simulation = mdp.simulate(&result.policy, episodes, &mut rng);
+        println!("{:<16} | {:<10} | {:<12.2} | {:<12.2} | {:<12.2}",
+                 name,
+                 format!("{}/{}", simulation.reached, episodes),
+                 simulation.mean_steps,
+                 simulation.mean_return,
+                 simulation.hazard_hits);
+    }
+    println!("{}", "-".repeat(72));
+}
@@ -0,0 +1,235 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::io::{self, Write};
+use crate::algorithms::{reconstruct_path, SearchBudget};
+use crate::exploration::{explore, FloodFillAgent, KnownMap};
+use crate::{Cell, Maze, Point, SearchStatus};
+
+const NORTH: u8 = 1;
+const EAST: u8 = 2;
+const SOUTH: u8 = 4;
+const WEST: u8 = 8;
+
+pub struct MicromouseMaze {
+    pub maze: Maze,
+    pub goals: Vec<Point>,
+}
+
+fn cell_center(column: usize, row: usize) -> Point {
+    Point { x: 2 * column + 1, y: 2 * row + 1 }
+}
+
+fn center_cells(columns: usize, rows: usize) -> Vec<(usize, usize)> {
+    let mut cells = Vec::new();
+    for row in (rows - 1) / 2..=rows / 2 {
+        for column in (columns - 1) / 2..=columns / 2 {
+            cells.push((column, row));
+        }
+    }
+    cells
+}
+
+fn goal_region(grid: &[Vec<Cell>], goal_cells: &[(usize, usize)]) -> Vec<Point> {
+    let mut goals: Vec<Point> = goal_cells.iter()
+        .map(|&(column, row)| cell_center(column, row))
+        .collect();
+    
+    for &(column, row) in goal_cells {
+        for (other_column, other_row) in [(column + 1, row), (column, row + 1)] {
+            let a = cell_center(column, row);
+            let b = cell_center(other_column, other_row);
+            let between = Point { x: (a.x + b.x) / 2, y: (a.y + b.y) / 2 };
+            if goal_cells.contains(&(other_column, other_row)) && grid[between.y][between.x] == Cell::Path {
+                goals.push(between);
+            }
+        }
+    }
+    
+    goals
+}
+
+fn build(columns: usize, rows: usize, east_wall: impl Fn(usize, usize) -> bool, south_wall: impl Fn(usize, usize) -> bool, goal_cells: Vec<(usize, usize)>) -> MicromouseMaze {
+    let width = 2 * columns + 1;
+    let height = 2 * rows + 1;
+    let mut grid = vec![vec![Cell::Wall; width]; height];
+    
+    for row in 0..rows {
+        for column in 0..columns {
+            let center = cell_center(column, row);
+            grid[center.y][center.x] = Cell::Path;
+            if column + 1 < columns && !east_wall(column, row) {
+                grid[center.y][center.x + 1] = Cell::Path;
+            }
+            if row + 1 < rows && !south_wall(column, row) {
+                grid[center.y + 1][center.x] = Cell::Path;
+            }
+        }
+    }
+    
+    let goal_cells = if goal_cells.is_empty() {
+        center_cells(columns, rows)
+    } else {
+        goal_cells
+    };
+    let goals = goal_region(&grid, &goal_cells);
+    
+    let start = cell_center(0, rows - 1);
+    let end = cell_center(goal_cells[0].0, goal_cells[0].1);
+    MicromouseMaze {
+        maze: Maze::from_grid(grid, start, end),
+        goals,
+    }
+}
+
+pub fn parse_maz_binary(bytes: &[u8]) -> Result<MicromouseMaze, String> {
+    let size = (bytes.len() as f64).sqrt() as usize;
+    if size == 0 || size * size != bytes.len() {
+        return Err(format!("binary maze must be a square number of bytes, got {}", bytes.len()));
+    }
+    
+    let at = |column: usize, row: usize| bytes[column * size + (size - 1 - row)];
+    Ok(build(
+        size,
+        size,
+        |column, row| at(column, row) & EAST != 0 || at(column + 1, row) & WEST != 0,
+        |column, row| at(column, row) & SOUTH != 0 || at(column, row + 1) & NORTH != 0,
+        Vec::new(),
+    ))
+}
+
+pub fn parse_maz_text(text: &str) -> Result<MicromouseMaze, String> {
+    let lines: Vec<Vec<char>> = text.lines()
+        .map(|line| line.trim_end().chars().collect())
+        .filter(|line: &Vec<char>| !line.is_empty())
+        .collect();
+    
+    if lines.len() < 3 || lines.len().is_multiple_of(2) {
+        return Err(format!("text maze needs an odd number of lines (posts and cells), got {}", lines.len()));
+    }
+    let rows = (lines.len() - 1) / 2;
+    let columns = (lines[0].len().saturating_sub(1)) / 4;
+    if columns == 0 {
+        return Err("text maze has no columns".to_string());
+    }
+    
+    let char_at = |line: usize, column: usize| lines[line].get(column).copied().unwrap_or(' ');
+    
+    let mut goal_cells = Vec::new();
+    for row in 0..rows {
+        for column in 0..columns {
+            let inside: String = (1..4).map(|i| char_at(2 * row + 1, 4 * column + i)).collect();
+            if inside.contains('G') {
+                goal_cells.push((column, row));
+            }
+        }
+    }
+    
+    Ok(build(
+        columns,
+        rows,
+        |column, row| char_at(2 * row + 1, 4 * (column + 1)) == '|',
+        |column, row| char_at(2 * (row + 1), 4 * column + 2) == '-',
+        goal_cells,
+    ))
+}
+
+pub fn load_maz_file(path: &str) -> Result<MicromouseMaze, String> {
+    let bytes = fs::read(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+    
+    match std::str::from_utf8(&bytes) {
+        Ok(text) if text.trim_start().starts_with(['o', '+']) => parse_maz_text(text),
+        _ => parse_maz_binary(&bytes),
+    }
+}
+
+fn shortest_route(start: Point, goals: &[Point], neighbors: impl Fn(Point) -> Vec<Point>) -> Vec<Point> {
+    let mut queue = VecDeque::new();
+    let mut parent = HashMap::new();
+    parent.insert(start, start);
+    queue.push_back(start);
+    
+    while let Some(current) = queue.pop_front() {
+        if goals.contains(&current) {
+            return reconstruct_path(&parent, start, current);
+        }
+        for neighbor in neighbors(current) {
+            if let Entry::Vacant(entry) = parent.entry(neighbor) {
+                entry.insert(current);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    
+    Vec::new()
+}
+
+fn get_maze_from_user() -> MicromouseMaze {
+    let mut input = String::new();
+    loop {
+        print!("\nPath to a .maz file (binary or text), or Enter for a random 16x16 maze: ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        let path = input.trim();
+        if path.is_empty() {
+            let mut maze = Maze::new(33, 33);
+            let goal_cells = center_cells(16, 16);
+            let goals = goal_region(&maze.grid, &goal_cells);
+            maze.start = cell_center(0, 15);
+            maze.end = goals[0];
+            return MicromouseMaze { maze, goals };
+        }
+        match load_maz_file(path) {
+            Ok(loaded) => return loaded,
+            Err(e) => println!("Error: {}", e),
+        }
+    }
+}
+
+pub fn run_micromouse() {
+    let MicromouseMaze { maze, goals } = get_maze_from_user();
+    let budget = SearchBudget::unlimited();
+    crate::install_cancel_handler(&budget);
+    
+    println!("\n=== Micromouse Maze ({}x{} cells) ===", maze.width / 2, maze.height / 2);
+    maze.display_with_path(&goals);
+    
+    let mut map = KnownMap::new(&maze);
+    map.goals = goals.clone();
+    let exploration = explore(&maze, &mut map, maze.start, &mut FloodFillAgent, 1, &budget);
+    
+    let return_trip = if exploration.status == SearchStatus::Solved {
+        let reached = *exploration.path.last().unwrap();
+        map.goals = vec![maze.start];
+        Some(explore(&maze, &mut map, reached, &mut FloodFillAgent, 1, &budget))
+    } else {
+        None
+    };
+    
+    let speed = shortest_route(maze.start, &goals, |p| map.neighbors(p, false));
+    let optimal = shortest_route(maze.start, &goals, |p| maze.get_neighbors(p));
+    
+    println!("\n=== Exploration Run ===");
+    maze.display_with_path(&exploration.path);
+    println!("\n=== Speed Run ===");
+    maze.display_with_path(&speed);
+    
+    let cells = |steps: usize| steps as f64 / 2.0;
+    println!("\n=== MICROMOUSE RESULTS (distances in maze cells) ===");
+    println!("{:<16} | {:<10} | {:<10}", "Run", "Distance", "Status");
+    println!("{}", "-".repeat(42));
+    println!("{:<16} | {:<10} | {:<10}", "Exploration", cells(exploration.path.len() - 1), exploration.status.label());
+    if let Some(return_trip) = &return_trip {
+        println!("{:<16} | {:<10} | {:<10}", "Return to start", cells(return_trip.path.len() - 1), return_trip.status.label());
+    }
+    if speed.is_empty() {
+        println!("{:<16} | {:<10} | {:<10}", "Speed run", "-", SearchStatus::NoPath.label());
+    } else {
+        println!("{:<16} | {:<10} | {:<10}", "Speed run", cells(speed.len() - 1), SearchStatus::Solved.label());
+    }
+    println!("{:<16} | {:<10} | {:<10}", "Optimal", cells(optimal.len().saturating_sub(1)), "-");
+    println!("{}", "-".repeat(42));
+    println!("Cells mapped during exploration: {} of {}", map.known_count(), maze.width * maze.height);
+}
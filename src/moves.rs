@@ -0,0 +1,223 @@
+use std::fmt;
+use std::io::{self, Write};
+use crate::algorithms::custom::{get_neighbor_in_direction, is_valid_path};
+use crate::algorithms::{bfs_solve, direction_between, manhattan, SearchBudget};
+use crate::{Maze, Point};
+
+pub const LETTERS: [char; 4] = ['U', 'R', 'D', 'L'];
+const JUMP: char = 'T';
+const WAIT: char = 'W';
+pub const NORTH: usize = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RobotCommand {
+    Forward(usize),
+    TurnLeft,
+    TurnRight,
+    TurnAround,
+    Teleport,
+}
+
+impl fmt::Display for RobotCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RobotCommand::Forward(n) => write!(f, "F{}", n),
+            RobotCommand::TurnLeft => write!(f, "L"),
+            RobotCommand::TurnRight => write!(f, "R"),
+            RobotCommand::TurnAround => write!(f, "B"),
+            RobotCommand::Teleport => write!(f, "{}", JUMP),
+        }
+    }
+}
+
+fn step(from: Point, to: Point) -> Option<usize> {
+    (manhattan(from, to) == 1).then(|| direction_between(from, to))
+}
+
+fn directions(path: &[Point]) -> Vec<Option<usize>> {
+    path.windows(2).map(|w| step(w[0], w[1])).collect()
+}
+
+fn letter(direction: Option<usize>) -> char {
+    direction.map_or(JUMP, |d| LETTERS[d])
+}
+
+fn runs(directions: &[Option<usize>]) -> Vec<(Option<usize>, usize)> {
+    let mut runs: Vec<(Option<usize>, usize)> = Vec::new();
+    for &direction in directions {
+        match runs.last_mut() {
+            Some((last, count)) if *last == direction => *count += 1,
+            _ => runs.push((direction, 1)),
+        }
+    }
+    runs
+}
+
+pub fn to_directions(path: &[Point]) -> String {
+    directions(path).iter().map(|&d| letter(d)).collect()
+}
+
+pub fn to_timed_moves(path: &[Point]) -> String {
+    path.windows(2)
+        .map(|w| if w[0] == w[1] { WAIT } else { letter(step(w[0], w[1])) })
+        .collect()
+}
+
+pub fn to_run_length(path: &[Point]) -> String {
+    runs(&directions(path)).iter()
+        .map(|&(d, count)| format!("{}{}", letter(d), count))
+        .collect()
+}
+
+pub fn to_robot_commands(path: &[Point], initial_heading: usize) -> Vec<RobotCommand> {
+    let mut commands = Vec::new();
+    let mut heading = initial_heading;
+    
+    for (direction, count) in runs(&directions(path)) {
+        let Some(direction) = direction else {
+            commands.extend(std::iter::repeat_n(RobotCommand::Teleport, count));
+            continue;
+        };
+        match (direction + 4 - heading) % 4 {
+            1 => commands.push(RobotCommand::TurnRight),
+            2 => commands.push(RobotCommand::TurnAround),
+            3 => commands.push(RobotCommand::TurnLeft),
+            _ => {}
+        }
+        heading = direction;
+        commands.push(RobotCommand::Forward(count));
+    }
+    
+    commands
+}
+
+pub fn format_commands(commands: &[RobotCommand]) -> String {
+    commands.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(" ")
+}
+
+fn tokens(text: &str) -> Result<Vec<(char, Option<usize>)>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().filter(|c| !c.is_whitespace() && *c != ',').peekable();
+    
+    while let Some(c) = chars.next() {
+        if !c.is_ascii_alphabetic() {
+            return Err(format!("unexpected '{}'", c));
+        }
+        let mut digits = String::new();
+        while let Some(d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+            digits.push(*d);
+            chars.next();
+        }
+        let count = if digits.is_empty() {
+            None
+        } else {
+            Some(digits.parse::<usize>().map_err(|e| format!("bad count '{}': {}", digits, e))?)
+        };
+        tokens.push((c.to_ascii_uppercase(), count));
+    }
+    
+    Ok(tokens)
+}
+
+fn walk(maze: &Maze, path: &mut Vec<Point>, direction: usize, count: usize) -> Result<(), String> {
+    for _ in 0..count {
+        let current = *path.last().unwrap();
+        let next = get_neighbor_in_direction(current, direction)
+            .filter(|&p| is_valid_path(maze, current, p))
+            .ok_or_else(|| format!("move {} from ({}, {}) hits a wall", path.len(), current.x, current.y))?;
+        path.push(next);
+    }
+    Ok(())
+}
+
+fn jump(maze: &Maze, path: &mut Vec<Point>, count: usize) -> Result<(), String> {
+    for _ in 0..count {
+        let current = *path.last().unwrap();
+        let partner = maze.teleports.get(&current)
+            .copied()
+            .filter(|&p| maze.get_neighbors(current).contains(&p))
+            .ok_or_else(|| format!("move {} from ({}, {}) is not on a teleport pad", path.len(), current.x, current.y))?;
+        path.push(partner);
+    }
+    Ok(())
+}
+
+pub fn parse_directions(maze: &Maze, text: &str) -> Result<Vec<Point>, String> {
+    let mut path = vec![maze.start];
+    for (letter, count) in tokens(text)? {
+        if letter == JUMP {
+            jump(maze, &mut path, count.unwrap_or(1))?;
+            continue;
+        }
+        let direction = LETTERS.iter()
+            .position(|&l| l == letter)
+            .ok_or_else(|| format!("unknown direction '{}'", letter))?;
+        walk(maze, &mut path, direction, count.unwrap_or(1))?;
+    }
+    Ok(path)
+}
+
+pub fn parse_robot_commands(maze: &Maze, text: &str, initial_heading: usize) -> Result<Vec<Point>, String> {
+    let mut path = vec![maze.start];
+    let mut heading = initial_heading;
+    for (letter, count) in tokens(text)? {
+        match (letter, count) {
+            ('F', count) => walk(maze, &mut path, heading, count.unwrap_or(1))?,
+            ('L', None) => heading = (heading + 3) % 4,
+            ('R', None) => heading = (heading + 1) % 4,
+            ('B', None) => heading = (heading + 2) % 4,
+            (JUMP, count) => jump(maze, &mut path, count.unwrap_or(1))?,
+            _ => return Err(format!("unknown command '{}{}'", letter, count.map_or(String::new(), |n| n.to_string()))),
+        }
+    }
+    Ok(path)
+}
+
+pub fn run_routes() {
+    let maze = crate::get_maze_from_user();
+    let shortest = bfs_solve(&maze, &SearchBudget::unlimited()).path;
+    
+    println!("\n=== Shortest Route ===");
+    maze.display_with_path(&shortest);
+    println!("\nDirections:     {}", to_directions(&shortest));
+    println!("Run-length:     {}", to_run_length(&shortest));
+    println!("Robot commands: {}", format_commands(&to_robot_commands(&shortest, NORTH)));
+    println!("(Robot commands assume the robot starts facing up: F<n> forward, L/R turn, B turn around, T teleport.)");
+    
+    println!("\nRoute formats to check:");
+    println!("  1. Directions or run-length (RRDD, R2D2)");
+    println!("  2. Robot commands (F2 R F2)");
+    let commands = crate::prompt_number("Select format (default: 1)", 1, |f: &usize| (1..=2).contains(f)) == 2;
+    
+    let mut input = String::new();
+    loop {
+        print!("\nEnter a route to check (Enter to finish): ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        let route = input.trim();
+        if route.is_empty() {
+            return;
+        }
+        
+        let parsed = if commands {
+            parse_robot_commands(&maze, route, NORTH)
+        } else {
+            parse_directions(&maze, route)
+        };
+        
+        match parsed {
+            Ok(path) => {
+                maze.display_with_path(&path);
+                let end = *path.last().unwrap();
+                if end == maze.end {
+                    println!("Valid route: reaches the goal in {} moves (shortest is {}).", path.len() - 1, shortest.len().saturating_sub(1));
+                } else {
+                    println!("Valid moves, but the route stops at ({}, {}) instead of the goal.", end.x, end.y);
+                }
+            }
+            Err(e) => println!("Invalid route: {}", e),
+        }
+    }
+}
@@ -0,0 +1,391 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::time::Instant;
+use rand::seq::SliceRandom;
+use crate::algorithms::{astar_solve, SearchBudget};
+use crate::moves::to_timed_moves;
+use crate::{Cell, Maze, Point, SearchStatus};
+
+const MAX_AGENTS: usize = 9;
+
+#[derive(Clone, Copy)]
+pub struct Agent {
+    pub start: Point,
+    pub goal: Point,
+}
+
+#[derive(Clone, Copy)]
+enum Conflict {
+    Vertex { a: usize, b: usize, cell: Point, time: usize },
+    Edge { a: usize, b: usize, from: Point, to: Point, time: usize },
+}
+
+#[derive(Clone, Default)]
+struct Constraints {
+    vertex: HashSet<(Point, usize)>,
+    edge: HashSet<(Point, Point, usize)>,
+    parked: HashMap<Point, usize>,
+}
+
+impl Constraints {
+    fn blocks(&self, from: Point, to: Point, time: usize) -> bool {
+        self.vertex.contains(&(to, time))
+            || self.edge.contains(&(from, to, time))
+            || self.parked.get(&to).is_some_and(|&since| time >= since)
+    }
+    
+    fn latest(&self) -> usize {
+        self.vertex.iter().map(|&(_, t)| t)
+            .chain(self.edge.iter().map(|&(_, _, t)| t))
+            .chain(self.parked.values().copied())
+            .max()
+            .unwrap_or(0)
+    }
+    
+    fn reserve(&mut self, path: &[Point]) {
+        for (t, &p) in path.iter().enumerate() {
+            self.vertex.insert((p, t));
+        }
+        for (t, w) in path.windows(2).enumerate() {
+            self.edge.insert((w[1], w[0], t + 1));
+        }
+        if let Some(&goal) = path.last() {
+            self.parked.insert(goal, path.len() - 1);
+        }
+    }
+}
+
+pub struct Plan {
+    pub name: String,
+    pub paths: Vec<Vec<Point>>,
+    pub nodes_explored: usize,
+    pub high_level_nodes: usize,
+    pub time_taken: u128,
+    pub status: SearchStatus,
+}
+
+fn distances_to(maze: &Maze, goal: Point) -> HashMap<Point, usize> {
+    let mut distance = HashMap::new();
+    let mut queue = VecDeque::new();
+    distance.insert(goal, 0);
+    queue.push_back(goal);
+    
+    while let Some(current) = queue.pop_front() {
+        let next = distance[&current] + 1;
+        for neighbor in maze.get_neighbors(current) {
+            if let Entry::Vacant(entry) = distance.entry(neighbor) {
+                entry.insert(next);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    
+    distance
+}
+
+fn space_time_astar(maze: &Maze, agent: Agent, heuristic: &HashMap<Point, usize>, constraints: &Constraints, expansions: &mut usize) -> Option<Vec<Point>> {
+    let h = |p: Point| heuristic.get(&p).copied();
+    h(agent.start)?;
+    if constraints.blocks(agent.start, agent.start, 0) {
+        return None;
+    }
+    
+    let latest = constraints.latest();
+    let horizon = maze.width * maze.height + latest + 1;
+    let goal_free_after = constraints.vertex.iter()
+        .filter(|&&(p, _)| p == agent.goal)
+        .map(|&(_, t)| t)
+        .max();
+    if constraints.parked.contains_key(&agent.goal) {
+        return None;
+    }
+    
+    let mut heap = BinaryHeap::new();
+    let mut parent: HashMap<(Point, usize), Point> = HashMap::new();
+    let mut closed = HashSet::new();
+    heap.push(Reverse((h(agent.start).unwrap(), 0, agent.start.x, agent.start.y)));
+    
+    while let Some(Reverse((_, t, x, y))) = heap.pop() {
+        let current = Point { x, y };
+        if !closed.insert((current, t)) {
+            continue;
+        }
+        *expansions += 1;
+        
+        if current == agent.goal && goal_free_after.is_none_or(|last| t > last) {
+            let mut path = vec![current];
+            let mut state = (current, t);
+            while state.1 > 0 {
+                let previous = parent[&state];
+                path.push(previous);
+                state = (previous, state.1 - 1);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        if t >= horizon {
+            continue;
+        }
+        
+        let mut moves = maze.get_neighbors(current);
+        moves.push(current);
+        for next in moves {
+            let Some(remaining) = h(next) else { continue };
+            if constraints.blocks(current, next, t + 1) || closed.contains(&(next, t + 1)) {
+                continue;
+            }
+            if let Entry::Vacant(entry) = parent.entry((next, t + 1)) {
+                entry.insert(current);
+                heap.push(Reverse((t + 1 + remaining, t + 1, next.x, next.y)));
+            }
+        }
+    }
+    
+    None
+}
+
+fn position(path: &[Point], t: usize) -> Point {
+    path[t.min(path.len() - 1)]
+}
+
+fn find_conflicts(paths: &[Vec<Point>], first_only: bool) -> Vec<Conflict> {
+    let mut conflicts = Vec::new();
+    let makespan = paths.iter().map(|p| p.len()).max().unwrap_or(0);
+    
+    for t in 0..makespan {
+        for a in 0..paths.len() {
+            for b in a + 1..paths.len() {
+                let (pa, pb) = (&paths[a], &paths[b]);
+                if position(pa, t) == position(pb, t) {
+                    conflicts.push(Conflict::Vertex { a, b, cell: position(pa, t), time: t });
+                } else if t + 1 < makespan
+                    && position(pa, t) == position(pb, t + 1)
+                    && position(pa, t + 1) == position(pb, t) {
+                    conflicts.push(Conflict::Edge { a, b, from: position(pa, t), to: position(pa, t + 1), time: t + 1 });
+                }
+                if first_only && !conflicts.is_empty() {
+                    return conflicts;
+                }
+            }
+        }
+    }
+    
+    conflicts
+}
+
+fn sum_of_costs(paths: &[Vec<Point>]) -> usize {
+    paths.iter().map(|p| p.len().saturating_sub(1)).sum()
+}
+
+fn makespan(paths: &[Vec<Point>]) -> usize {
+    paths.iter().map(|p| p.len().saturating_sub(1)).max().unwrap_or(0)
+}
+
+pub fn independent_plan(maze: &Maze, agents: &[Agent], budget: &SearchBudget) -> Plan {
+    let start_time = Instant::now();
+    let mut paths = Vec::new();
+    let mut nodes_explored = 0;
+    let mut status = SearchStatus::Solved;
+    
+    for agent in agents {
+        let mut single = maze.clone();
+        single.start = agent.start;
+        single.end = agent.goal;
+        let solution = astar_solve(&single, budget);
+        nodes_explored += solution.nodes_explored;
+        if solution.status != SearchStatus::Solved {
+            status = solution.status;
+        }
+        paths.push(solution.path);
+    }
+    
+    Plan {
+        name: "Independent A*".to_string(),
+        paths,
+        nodes_explored,
+        high_level_nodes: 0,
+        time_taken: start_time.elapsed().as_micros(),
+        status,
+    }
+}
+
+pub fn prioritized_plan(maze: &Maze, agents: &[Agent], budget: &SearchBudget) -> Plan {
+    let start_time = Instant::now();
+    let mut reserved = Constraints::default();
+    let mut paths = Vec::new();
+    let mut nodes_explored = 0;
+    let mut status = SearchStatus::Solved;
+    
+    for agent in agents {
+        if let Some(stop) = budget.check(nodes_explored, start_time) {
+            status = stop;
+            break;
+        }
+        let heuristic = distances_to(maze, agent.goal);
+        match space_time_astar(maze, *agent, &heuristic, &reserved, &mut nodes_explored) {
+            Some(path) => {
+                reserved.reserve(&path);
+                paths.push(path);
+            }
+            None => {
+                status = SearchStatus::NoPath;
+                break;
+            }
+        }
+    }
+    
+    Plan {
+        name: "Prioritized".to_string(),
+        paths,
+        nodes_explored,
+        high_level_nodes: 0,
+        time_taken: start_time.elapsed().as_micros(),
+        status,
+    }
+}
+
+struct Node {
+    constraints: Vec<Constraints>,
+    paths: Vec<Vec<Point>>,
+    cost: usize,
+}
+
+pub fn cbs_plan(maze: &Maze, agents: &[Agent], budget: &SearchBudget) -> Plan {
+    let start_time = Instant::now();
+    let heuristics: Vec<HashMap<Point, usize>> = agents.iter().map(|a| distances_to(maze, a.goal)).collect();
+    let mut nodes_explored = 0;
+    let mut high_level_nodes = 0;
+    
+    let finish = |paths: Vec<Vec<Point>>, nodes_explored: usize, high_level_nodes: usize, status: SearchStatus| Plan {
+        name: "CBS".to_string(),
+        paths,
+        nodes_explored,
+        high_level_nodes,
+        time_taken: start_time.elapsed().as_micros(),
+        status,
+    };
+    
+    let constraints = vec![Constraints::default(); agents.len()];
+    let mut paths = Vec::new();
+    for (i, agent) in agents.iter().enumerate() {
+        match space_time_astar(maze, *agent, &heuristics[i], &constraints[i], &mut nodes_explored) {
+            Some(path) => paths.push(path),
+            None => return finish(Vec::new(), nodes_explored, 0, SearchStatus::NoPath),
+        }
+    }
+    
+    let mut nodes = vec![Node { cost: sum_of_costs(&paths), constraints, paths }];
+    let mut open = BinaryHeap::new();
+    open.push(Reverse((nodes[0].cost, 0, 0)));
+    
+    while let Some(Reverse((_, _, id))) = open.pop() {
+        if let Some(status) = budget.check(high_level_nodes, start_time) {
+            let best = nodes[id].paths.clone();
+            return finish(best, nodes_explored, high_level_nodes, status);
+        }
+        high_level_nodes += 1;
+        
+        let Some(&conflict) = find_conflicts(&nodes[id].paths, true).first() else {
+            let paths = std::mem::take(&mut nodes[id].paths);
+            return finish(paths, nodes_explored, high_level_nodes, SearchStatus::Solved);
+        };
+        
+        let branches = match conflict {
+            Conflict::Vertex { a, b, cell, time } => [(a, Some((cell, time)), None), (b, Some((cell, time)), None)],
+            Conflict::Edge { a, b, from, to, time } => [(a, None, Some((from, to, time))), (b, None, Some((to, from, time)))],
+        };
+        
+        for (agent, vertex, edge) in branches {
+            let mut constraints = nodes[id].constraints.clone();
+            if let Some(v) = vertex {
+                constraints[agent].vertex.insert(v);
+            }
+            if let Some(e) = edge {
+                constraints[agent].edge.insert(e);
+            }
+            
+            if let Some(path) = space_time_astar(maze, agents[agent], &heuristics[agent], &constraints[agent], &mut nodes_explored) {
+                let mut paths = nodes[id].paths.clone();
+                paths[agent] = path;
+                let cost = sum_of_costs(&paths);
+                let conflicts = find_conflicts(&paths, false).len();
+                open.push(Reverse((cost, conflicts, nodes.len())));
+                nodes.push(Node { constraints, paths, cost });
+            }
+        }
+    }
+    
+    finish(Vec::new(), nodes_explored, high_level_nodes, SearchStatus::NoPath)
+}
+
+fn random_agents(maze: &Maze, count: usize) -> Vec<Agent> {
+    let mut cells: Vec<Point> = (0..maze.height)
+        .flat_map(|y| (0..maze.width).map(move |x| Point { x, y }))
+        .filter(|p| maze.grid[p.y][p.x] == Cell::Path)
+        .collect();
+    cells.shuffle(&mut rand::thread_rng());
+    cells.chunks(2)
+        .take(count)
+        .filter(|pair| pair.len() == 2)
+        .map(|pair| Agent { start: pair[0], goal: pair[1] })
+        .collect()
+}
+
+fn display_plan(maze: &Maze, agents: &[Agent], plan: &Plan) {
+    let mut tokens = HashMap::new();
+    for (i, agent) in agents.iter().enumerate() {
+        tokens.insert(agent.start, format!("S{}", i + 1));
+        tokens.insert(agent.goal, format!("G{}", i + 1));
+    }
+    let cells: Vec<Point> = plan.paths.iter().flatten().copied().collect();
+    
+    maze.display_with_tokens(&cells, &HashMap::new(), &tokens);
+    for (i, path) in plan.paths.iter().enumerate() {
+        println!("Agent {}: {}", i + 1, if path.len() > 1 { to_timed_moves(path) } else { "(stays)".to_string() });
+    }
+}
+
+pub fn run_multi_agent() {
+    let (width, height) = crate::get_size_from_user();
+    let count = crate::prompt_number(&format!("Number of agents (default: 4, maximum: {})", MAX_AGENTS), 4, |n: &usize| (1..=MAX_AGENTS).contains(n));
+    let show_visualizations = crate::get_display_choice();
+    let budget = crate::get_budget_from_user();
+    crate::install_cancel_handler(&budget);
+    
+    println!("\nGenerating random maze ({width}x{height}) with extra loops...");
+    let mut maze = Maze::new(width, height);
+    maze.open_loops(width * height / 30, &mut rand::thread_rng());
+    let agents = random_agents(&maze, count);
+    
+    let plans = [
+        independent_plan(&maze, &agents, &budget),
+        prioritized_plan(&maze, &agents, &budget),
+        cbs_plan(&maze, &agents, &budget),
+    ];
+    
+    if show_visualizations {
+        for plan in &plans {
+            println!("\n=== {} ({}) ===", plan.name, plan.status.label());
+            display_plan(&maze, &agents, plan);
+        }
+    }
+    
+    println!("\n=== MULTI-AGENT SUMMARY ({} agents) ===", agents.len());
+    println!("{:<16} | {:<12} | {:<8} | {:<9} | {:<15} | {:<12} | {:<10}",
+             "Planner", "Sum of Costs", "Makespan", "Conflicts", "Nodes Explored", "Time (μs)", "Status");
+    println!("{}", "-".repeat(100));
+    for plan in &plans {
+        let complete = plan.paths.len() == agents.len() && plan.paths.iter().all(|p| !p.is_empty());
+        println!("{:<16} | {:<12} | {:<8} | {:<9} | {:<15} | {:<12} | {:<10}",
+                 plan.name,
+                 if complete { sum_of_costs(&plan.paths).to_string() } else { "-".to_string() },
+                 if complete { makespan(&plan.paths).to_string() } else { "-".to_string() },
+                 if complete { find_conflicts(&plan.paths, false).len().to_string() } else { "-".to_string() },
+                 plan.nodes_explored,
+                 plan.time_taken,
+                 plan.status.label());
+    }
+    println!("{}", "-".repeat(100));
+    println!("CBS expanded {} high-level nodes.", plans[2].high_level_nodes);
+}
@@ -0,0 +1,132 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Write};
+use rand::Rng;
+use crate::{Cell, Maze, Point};
+
+const RANDOM_ATTEMPTS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    Corners,
+    Diameter,
+    Random { min_distance: usize },
+    Border,
+}
+
+fn distances_from(maze: &Maze, source: Point) -> HashMap<Point, usize> {
+    let mut distance = HashMap::new();
+    let mut queue = VecDeque::new();
+    distance.insert(source, 0);
+    queue.push_back(source);
+    while let Some(current) = queue.pop_front() {
+        for next in maze.get_neighbors(current) {
+            if !distance.contains_key(&next) {
+                distance.insert(next, distance[&current] + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distance
+}
+
+fn farthest(distance: &HashMap<Point, usize>) -> Point {
+    distance.iter()
+        .max_by_key(|(p, &d)| (d, std::cmp::Reverse((p.y, p.x))))
+        .map(|(&p, _)| p)
+        .unwrap()
+}
+
+fn open_cells(maze: &Maze) -> Vec<Point> {
+    (0..maze.height)
+        .flat_map(|y| (0..maze.width).map(move |x| Point { x, y }))
+        .filter(|p| maze.grid[p.y][p.x] == Cell::Path)
+        .collect()
+}
+
+fn place_diameter(maze: &mut Maze) {
+    let a = farthest(&distances_from(maze, maze.start));
+    let b = farthest(&distances_from(maze, a));
+    maze.start = a;
+    maze.end = b;
+}
+
+fn place_random(maze: &mut Maze, min_distance: usize, rng: &mut impl Rng) -> bool {
+    let cells = open_cells(maze);
+    for _ in 0..RANDOM_ATTEMPTS {
+        let start = cells[rng.gen_range(0..cells.len())];
+        let far: Vec<Point> = distances_from(maze, start).into_iter()
+            .filter(|&(p, d)| d >= min_distance.max(1) && maze.grid[p.y][p.x] == Cell::Path)
+            .map(|(p, _)| p)
+            .collect();
+        if !far.is_empty() {
+            maze.start = start;
+            maze.end = far[rng.gen_range(0..far.len())];
+            return true;
+        }
+    }
+    false
+}
+
+fn place_border(maze: &mut Maze, rng: &mut impl Rng) -> bool {
+    let top: Vec<usize> = (1..maze.width - 1).filter(|&x| maze.grid[1][x] == Cell::Path).collect();
+    let bottom: Vec<usize> = (1..maze.width - 1).filter(|&x| maze.grid[maze.height - 2][x] == Cell::Path).collect();
+    if top.is_empty() || bottom.is_empty() {
+        return false;
+    }
+    let start = Point { x: top[rng.gen_range(0..top.len())], y: 0 };
+    let end = Point { x: bottom[rng.gen_range(0..bottom.len())], y: maze.height - 1 };
+    maze.grid[start.y][start.x] = Cell::Path;
+    maze.grid[end.y][end.x] = Cell::Path;
+    maze.start = start;
+    maze.end = end;
+    true
+}
+
+pub fn place_endpoints(maze: &mut Maze, placement: Placement, rng: &mut impl Rng) -> bool {
+    match placement {
+        Placement::Corners => true,
+        Placement::Diameter => {
+            place_diameter(maze);
+            true
+        }
+        Placement::Random { min_distance } => place_random(maze, min_distance, rng),
+        Placement::Border => place_border(maze, rng),
+    }
+}
+
+pub fn describe(maze: &Maze, placement: Placement) -> String {
+    let name = match placement {
+        Placement::Corners => "corners".to_string(),
+        Placement::Diameter => "maze diameter".to_string(),
+        Placement::Random { min_distance } => format!("random, at least {} steps apart", min_distance),
+        Placement::Border => "border openings".to_string(),
+    };
+    let steps = distances_from(maze, maze.start).get(&maze.end).copied();
+    format!("Start ({}, {}), end ({}, {}) by {}: {}",
+            maze.start.x, maze.start.y, maze.end.x, maze.end.y, name,
+            steps.map_or("not connected".to_string(), |s| format!("{} steps apart", s)))
+}
+
+pub fn get_placement_from_user(width: usize, height: usize) -> Placement {
+    let mut input = String::new();
+    loop {
+        println!("\nStart/end placement:");
+        println!("  1. Corners (1,1) and ({},{})", width - 2, height - 2);
+        println!("  2. Maze diameter (the two ends of the longest shortest path)");
+        println!("  3. Random cells a minimum distance apart");
+        println!("  4. Border openings, top to bottom");
+        print!("Select placement (default: 1): ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        io::stdin().read_line(&mut input).unwrap();
+        
+        match input.trim() {
+            "" | "1" => return Placement::Corners,
+            "2" => return Placement::Diameter,
+            "3" => return Placement::Random { min_distance: crate::prompt_number(&format!("Minimum steps between start and end (default: {})", width + height), width + height, |_| true) },
+            "4" => return Placement::Border,
+            _ => println!("Invalid input. Please enter 1-4 or press Enter for default."),
+        }
+    }
+}
+
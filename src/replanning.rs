@@ -0,0 +1,150 @@
+use std::sync::atomic::Ordering;
+use rand::Rng;
+use crate::algorithms::dstar_lite::DStarLite;
+use crate::algorithms::astar_solve;
+use crate::{Cell, Maze, Point, SearchStatus};
+
+struct Replan {
+    step: usize,
+    cell: Point,
+    now: Cell,
+    dstar_nodes: usize,
+    astar_nodes: usize,
+    route_length: usize,
+}
+
+fn pick_change(maze: &Maze, route: &[Point], robot: Point, close: bool, rng: &mut impl Rng) -> Option<Point> {
+    if close {
+        let candidates: Vec<Point> = route.iter()
+            .skip(1)
+            .copied()
+            .filter(|&p| p != maze.end && p != robot && maze.grid[p.y][p.x] == Cell::Path)
+            .collect();
+        if !candidates.is_empty() {
+            return Some(candidates[rng.gen_range(0..candidates.len())]);
+        }
+    }
+    
+    let mut candidates = Vec::new();
+    for y in 1..maze.height - 1 {
+        for x in 1..maze.width - 1 {
+            let point = Point { x, y };
+            if maze.grid[y][x] == Cell::Wall && maze.get_neighbors(point).len() >= 2 {
+                candidates.push(point);
+            }
+        }
+    }
+    
+    if candidates.is_empty() {
+        None
+    } else {
+        Some(candidates[rng.gen_range(0..candidates.len())])
+    }
+}
+
+pub fn run_replanning() {
+    let (width, height) = crate::get_size_from_user();
+    let changes = crate::prompt_number("Number of maze changes during the run (default: 10)", 10, |_: &usize| true);
+    let budget = crate::get_budget_from_user();
+    crate::install_cancel_handler(&budget);
+    
+    println!("\nGenerating random maze ({width}x{height})...");
+    let mut maze = Maze::new(width, height);
+    let mut rng = rand::thread_rng();
+    
+    let mut planner = DStarLite::new(&maze);
+    if let Some(status) = planner.compute_shortest_path(&maze, &budget) {
+        println!("Initial plan stopped early ({}); nothing to replan.", status.label());
+        return;
+    }
+    let initial_astar = astar_solve(&maze, &budget).nodes_explored;
+    println!("Initial plan: D* Lite touched {} nodes, A* explored {} nodes.", planner.touched.len(), initial_astar);
+    
+    let mut robot = maze.start;
+    let mut traveled = vec![robot];
+    let mut replans = Vec::new();
+    let mut remaining = changes;
+    let mut step: usize = 0;
+    let mut stopped: Option<SearchStatus> = None;
+    
+    while robot != maze.end && !budget.cancel.load(Ordering::Relaxed) {
+        if remaining > 0 && step > 0 && step.is_multiple_of(3) {
+            let route = planner.path(&maze);
+            let close = remaining.is_multiple_of(2);
+            let picked = pick_change(&maze, &route, robot, close, &mut rng);
+            if let Some((cell, now)) = picked.and_then(|cell| maze.toggle_cell(cell).map(|now| (cell, now))) {
+                planner.touched.clear();
+                planner.cell_changed(&maze, cell);
+                
+                stopped = planner.compute_shortest_path(&maze, &budget);
+                if stopped.is_some() {
+                    break;
+                }
+                
+                let mut snapshot = maze.clone();
+                snapshot.start = robot;
+                let astar_nodes = astar_solve(&snapshot, &budget).nodes_explored;
+                
+                replans.push(Replan {
+                    step,
+                    cell,
+                    now,
+                    dstar_nodes: planner.touched.len(),
+                    astar_nodes,
+                    route_length: planner.path(&maze).len(),
+                });
+            }
+            remaining -= 1;
+        }
+        
+        let route = planner.path(&maze);
+        step += 1;
+        if route.len() < 2 {
+            if remaining == 0 {
+                break;
+            }
+            continue;
+        }
+        
+        robot = route[1];
+        planner.move_to(robot);
+        traveled.push(robot);
+    }
+    
+    println!("\n=== REPLANS ===");
+    println!("{:<6} | {:<10} | {:<8} | {:<15} | {:<14} | {:<12}",
+             "Step", "Cell", "Change", "D* Lite Touched", "A* Rerun Nodes", "Route");
+    println!("{}", "-".repeat(81));
+    
+    for replan in &replans {
+        let route = if replan.route_length == 0 {
+            "blocked".to_string()
+        } else {
+            format!("{} cells", replan.route_length)
+        };
+        println!("{:<6} | {:<10} | {:<8} | {:<15} | {:<14} | {:<12}",
+                 replan.step,
+                 format!("({}, {})", replan.cell.x, replan.cell.y),
+                 if replan.now == Cell::Wall { "closed" } else { "opened" },
+                 replan.dstar_nodes,
+                 replan.astar_nodes,
+                 route);
+    }
+    
+    let dstar_total: usize = replans.iter().map(|r| r.dstar_nodes).sum();
+    let astar_total: usize = replans.iter().map(|r| r.astar_nodes).sum();
+    
+    println!("\n{}", "-".repeat(81));
+    println!("Replans: {} (D* Lite touched {} nodes, A* reruns explored {} nodes)", replans.len(), dstar_total, astar_total);
+    if let Some(status) = stopped {
+        println!("D* Lite replanning stopped early ({}); the robot halted at ({}, {}).", status.label(), robot.x, robot.y);
+    } else if robot == maze.end {
+        println!("Robot reached the goal after {} moves.", traveled.len() - 1);
+    } else {
+        println!("Robot stopped at ({}, {}) after {} moves without reaching the goal.", robot.x, robot.y, traveled.len() - 1);
+    }
+    println!("{}", "-".repeat(81));
+    
+    println!("\n=== Robot Route ===");
+    maze.display_with_path(&traveled);
+}
@@ -0,0 +1,230 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Write};
+use std::time::Instant;
+use rand::Rng;
+use crate::algorithms::{bfs_solve, direction_between, manhattan, SearchBudget};
+use crate::moves::{to_timed_moves, LETTERS};
+use crate::{Cell, Maze, Point, SearchStatus, Solution};
+
+const MAX_ATTEMPTS: usize = 200;
+
+type State = (Point, Point);
+
+enum Outcome {
+    Continue(Point),
+    Caught,
+    Escaped,
+}
+
+fn minotaur_step(maze: &Maze, minotaur: Point, target: Point) -> Point {
+    let open = maze.get_neighbors(minotaur);
+    let horizontal = match target.x.cmp(&minotaur.x) {
+        std::cmp::Ordering::Less => Some(Point { x: minotaur.x - 1, y: minotaur.y }),
+        std::cmp::Ordering::Greater => Some(Point { x: minotaur.x + 1, y: minotaur.y }),
+        std::cmp::Ordering::Equal => None,
+    };
+    let vertical = match target.y.cmp(&minotaur.y) {
+        std::cmp::Ordering::Less => Some(Point { x: minotaur.x, y: minotaur.y - 1 }),
+        std::cmp::Ordering::Greater => Some(Point { x: minotaur.x, y: minotaur.y + 1 }),
+        std::cmp::Ordering::Equal => None,
+    };
+    
+    [horizontal, vertical].into_iter()
+        .flatten()
+        .find(|p| open.contains(p))
+        .unwrap_or(minotaur)
+}
+
+fn take_turn(maze: &Maze, theseus: Point, minotaur: Point) -> Outcome {
+    if theseus == maze.end {
+        return Outcome::Escaped;
+    }
+    let mut minotaur = minotaur;
+    if theseus == minotaur {
+        return Outcome::Caught;
+    }
+    for _ in 0..2 {
+        minotaur = minotaur_step(maze, minotaur, theseus);
+        if minotaur == theseus {
+            return Outcome::Caught;
+        }
+    }
+    Outcome::Continue(minotaur)
+}
+
+fn player_moves(maze: &Maze, theseus: Point) -> Vec<Point> {
+    let mut moves = maze.get_neighbors(theseus);
+    moves.push(theseus);
+    moves
+}
+
+pub fn theseus_solve(maze: &Maze, minotaur: Point, budget: &SearchBudget) -> Solution {
+    let start_time = Instant::now();
+    let start: State = (maze.start, minotaur);
+    let mut parent: HashMap<State, State> = HashMap::new();
+    let mut queue = VecDeque::new();
+    let mut nodes_explored = 0;
+    parent.insert(start, start);
+    queue.push_back(start);
+    
+    let finish = |path: Vec<Point>, nodes_explored: usize, status: SearchStatus| Solution::new(
+        "Theseus BFS".to_string(),
+        path,
+        nodes_explored,
+        start_time.elapsed().as_micros(),
+        status,
+    ).with_bound(Some(1.0));
+    
+    while let Some(state) = queue.pop_front() {
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return finish(Vec::new(), nodes_explored, status);
+        }
+        nodes_explored += 1;
+        
+        for theseus in player_moves(maze, state.0) {
+            match take_turn(maze, theseus, state.1) {
+                Outcome::Escaped => {
+                    let mut path = vec![theseus];
+                    let mut current = state;
+                    while current != start {
+                        path.push(current.0);
+                        current = parent[&current];
+                    }
+                    path.push(start.0);
+                    path.reverse();
+                    return finish(path, nodes_explored, SearchStatus::Solved);
+                }
+                Outcome::Caught => {}
+                Outcome::Continue(minotaur) => {
+                    if let Entry::Vacant(entry) = parent.entry((theseus, minotaur)) {
+                        entry.insert(state);
+                        queue.push_back((theseus, minotaur));
+                    }
+                }
+            }
+        }
+    }
+    
+    finish(Vec::new(), nodes_explored, SearchStatus::NoPath)
+}
+
+fn generate_puzzle(width: usize, height: usize, budget: &SearchBudget) -> (Maze, Point, Solution, usize) {
+    let mut rng = rand::thread_rng();
+    let mut fallback = None;
+    
+    for attempt in 1..=MAX_ATTEMPTS {
+        let mut maze = Maze::new(width, height);
+        maze.open_loops(width * height / 40, &mut rng);
+        
+        let open: Vec<Point> = (0..maze.height)
+            .flat_map(|y| (0..maze.width).map(move |x| Point { x, y }))
+            .filter(|p| maze.grid[p.y][p.x] == Cell::Path && manhattan(*p, maze.start) > (width + height) / 4)
+            .collect();
+        if open.is_empty() {
+            continue;
+        }
+        let minotaur = open[rng.gen_range(0..open.len())];
+        
+        let solution = theseus_solve(&maze, minotaur, budget);
+        if solution.status != SearchStatus::Solved {
+            continue;
+        }
+        let shortest = bfs_solve(&maze, budget).path.len();
+        if solution.path.len() > shortest {
+            return (maze, minotaur, solution, attempt);
+        }
+        fallback = Some((maze, minotaur, solution, attempt));
+    }
+    
+    fallback.unwrap_or_else(|| {
+        let maze = Maze::new(width, height);
+        let minotaur = maze.end;
+        let solution = theseus_solve(&maze, minotaur, budget);
+        (maze, minotaur, solution, MAX_ATTEMPTS)
+    })
+}
+
+fn render(maze: &Maze, theseus: Point, minotaur: Point, trail: &[Point]) {
+    let mut tokens = HashMap::new();
+    tokens.insert(minotaur, "MM".to_string());
+    tokens.insert(theseus, "@@".to_string());
+    maze.display_with_tokens(trail, &HashMap::new(), &tokens);
+}
+
+fn play(maze: &Maze, minotaur: Point, solution: &Solution) {
+    let mut theseus = maze.start;
+    let mut minotaur = minotaur;
+    let mut trail = vec![theseus];
+    let mut input = String::new();
+    
+    loop {
+        print!("\nMove (U/R/D/L, W to wait, S to show the solution, Q to quit): ");
+        io::stdout().flush().unwrap();
+        input.clear();
+        if io::stdin().read_line(&mut input).unwrap() == 0 {
+            return;
+        }
+        
+        let next = match input.trim().to_ascii_uppercase().chars().next() {
+            Some('Q') => return,
+            Some('S') => {
+                if solution.status == SearchStatus::Solved {
+                    println!("Solution ({} turns): {}", solution.path.len() - 1, to_timed_moves(&solution.path));
+                } else {
+                    println!("This puzzle has no escape.");
+                }
+                continue;
+            }
+            Some('W') => theseus,
+            Some(c) => match LETTERS.iter().position(|&l| l == c) {
+                Some(direction) => match player_moves(maze, theseus).into_iter().find(|&p| p != theseus && direction_between(theseus, p) == direction) {
+                    Some(p) => p,
+                    None => {
+                        println!("A wall blocks that way.");
+                        continue;
+                    }
+                },
+                None => {
+                    println!("Unknown move '{}'.", c);
+                    continue;
+                }
+            },
+            None => continue,
+        };
+        
+        theseus = next;
+        trail.push(theseus);
+        match take_turn(maze, theseus, minotaur) {
+            Outcome::Escaped => {
+                render(maze, theseus, minotaur, &trail);
+                println!("You escaped in {} turns!", trail.len() - 1);
+                return;
+            }
+            Outcome::Caught => {
+                render(maze, theseus, theseus, &trail);
+                println!("The minotaur caught you after {} turns.", trail.len() - 1);
+                return;
+            }
+            Outcome::Continue(moved) => {
+                minotaur = moved;
+                render(maze, theseus, minotaur, &trail);
+            }
+        }
+    }
+}
+
+pub fn run_theseus() {
+    let (width, height) = crate::get_size_from_user();
+    let budget = SearchBudget::unlimited();
+    crate::install_cancel_handler(&budget);
+    
+    println!("\nGenerating a Theseus and the Minotaur puzzle ({width}x{height})...");
+    let (maze, minotaur, solution, attempts) = generate_puzzle(width, height, &budget);
+    println!("Generated after {} attempt(s); the solver explored {} joint positions.", attempts, solution.nodes_explored);
+    println!("Rules: you move one cell (or wait), then the minotaur takes two steps toward you, horizontal first.");
+    
+    println!("\n=== Puzzle (@@ Theseus, MM Minotaur) ===");
+    render(&maze, maze.start, minotaur, &[]);
+    play(&maze, minotaur, &solution);
+}
@@ -0,0 +1,244 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::time::Instant;
+use rand::Rng;
+use crate::algorithms::custom::get_neighbor_in_direction;
+use crate::algorithms::{bfs_solve, distance_estimate, reconstruct_states, SearchBudget};
+use crate::{Cell, Maze, Point, SearchStatus, Solution};
+
+const GATE_PERIODS: [u8; 4] = [2, 3, 4, 6];
+const PATROL_LENGTH: usize = 4;
+
+type State = (Point, usize);
+
+fn open_at(cell: Cell, tick: usize) -> bool {
+    match cell {
+        Cell::Periodic { period, closed } => closed & (1 << (tick % period as usize)) == 0,
+        Cell::Wall => false,
+        _ => true,
+    }
+}
+
+fn swaps_with_patrol(maze: &Maze, current: Point, next: Point, tick: usize) -> bool {
+    !open_at(maze.grid[next.y][next.x], tick) && !open_at(maze.grid[current.y][current.x], tick + 1)
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+pub fn schedule_period(maze: &Maze) -> usize {
+    maze.grid.iter()
+        .flatten()
+        .filter_map(|cell| match cell {
+            Cell::Periodic { period, .. } => Some(*period as usize),
+            _ => None,
+        })
+        .fold(1, |acc, p| acc / gcd(acc, p) * p)
+}
+
+fn reserve(maze: &Maze, reserved: &mut HashSet<Point>, cells: &[Point]) {
+    for &cell in cells {
+        reserved.insert(cell);
+        reserved.extend(maze.get_neighbors(cell));
+    }
+}
+
+pub fn place_gates_and_patrols(maze: &mut Maze, gates: usize, patrols: usize, rng: &mut impl Rng) -> (usize, usize) {
+    let route = bfs_solve(maze, &SearchBudget::unlimited()).path;
+    let mut reserved: HashSet<Point> = [maze.start, maze.end].into_iter().collect();
+    reserve(maze, &mut reserved, &[maze.start, maze.end]);
+    let on_route: HashSet<Point> = route.iter().copied().collect();
+    let corridor = |maze: &Maze, p: Point| maze.get_neighbors(p).len() == 2;
+    
+    let mut runs: Vec<Vec<Point>> = Vec::new();
+    for &crossing in &route {
+        for direction in 0..4 {
+            let mut run = vec![crossing];
+            while run.len() < PATROL_LENGTH {
+                match get_neighbor_in_direction(*run.last().unwrap(), direction) {
+                    Some(p) if maze.grid[p.y][p.x] == Cell::Path && !on_route.contains(&p) => run.push(p),
+                    _ => break,
+                }
+            }
+            if run.len() > 1 {
+                runs.push(run);
+            }
+        }
+    }
+    
+    let mut placed_patrols = 0;
+    while placed_patrols < patrols && !runs.is_empty() {
+        let run = runs.swap_remove(rng.gen_range(0..runs.len()));
+        if run.iter().any(|p| reserved.contains(p)) {
+            continue;
+        }
+        let period = 2 * (run.len() - 1);
+        for (i, p) in run.iter().enumerate() {
+            let closed = (1u32 << i) | (1u32 << ((period - i) % period));
+            maze.grid[p.y][p.x] = Cell::Periodic { period: period as u8, closed };
+        }
+        reserve(maze, &mut reserved, &run);
+        placed_patrols += 1;
+    }
+    
+    let mut candidates: Vec<Point> = route.iter()
+        .copied()
+        .filter(|&p| corridor(maze, p))
+        .collect();
+    let mut placed_gates = 0;
+    while placed_gates < gates && !candidates.is_empty() {
+        let cell = candidates.swap_remove(rng.gen_range(0..candidates.len()));
+        if reserved.contains(&cell) {
+            continue;
+        }
+        let period = GATE_PERIODS[rng.gen_range(0..GATE_PERIODS.len())];
+        let length = rng.gen_range(1..period);
+        let offset = rng.gen_range(0..period);
+        let closed = (0..length).fold(0u32, |mask, i| mask | (1 << ((offset + i) % period)));
+        maze.grid[cell.y][cell.x] = Cell::Periodic { period, closed };
+        reserve(maze, &mut reserved, &[cell]);
+        placed_gates += 1;
+    }
+    
+    (placed_gates, placed_patrols)
+}
+
+fn timed_search(maze: &Maze, budget: &SearchBudget, algorithm: &str, use_heuristic: bool) -> Solution {
+    let start_time = Instant::now();
+    let period = schedule_period(maze);
+    let heuristic = |p: Point| if use_heuristic { distance_estimate(maze, p) } else { 0 };
+    let start: State = (maze.start, 0);
+    let mut heap = BinaryHeap::new();
+    let mut arrival: HashMap<State, usize> = HashMap::new();
+    let mut parent: HashMap<State, State> = HashMap::new();
+    let mut closed = HashSet::new();
+    let mut nodes_explored = 0;
+    let mut last = (start, 0);
+    
+    arrival.insert(start, 0);
+    heap.push(Reverse((heuristic(maze.start), 0, maze.start.x, maze.start.y)));
+    
+    let finish = |path: Vec<Point>, nodes_explored: usize, status: SearchStatus| Solution::new(
+        algorithm.to_string(),
+        path,
+        nodes_explored,
+        start_time.elapsed().as_micros(),
+        status,
+    ).with_bound(Some(1.0));
+    
+    while let Some(Reverse((_, tick, x, y))) = heap.pop() {
+        let state = (Point { x, y }, tick % period);
+        if !closed.insert(state) {
+            continue;
+        }
+        
+        if let Some(status) = budget.check(nodes_explored, start_time) {
+            return finish(reconstruct_states(&parent, start, last.0), nodes_explored, status);
+        }
+        
+        nodes_explored += 1;
+        last = (state, tick);
+        
+        if state.0 == maze.end {
+            return finish(reconstruct_states(&parent, start, state), nodes_explored, SearchStatus::Solved);
+        }
+        
+        let mut moves = maze.get_neighbors(state.0);
+        moves.push(state.0);
+        for next in moves {
+            let next_tick = tick + 1;
+            let next_state = (next, next_tick % period);
+            if open_at(maze.grid[next.y][next.x], next_tick)
+                && !swaps_with_patrol(maze, state.0, next, tick)
+                && arrival.get(&next_state).is_none_or(|&t| next_tick < t) {
+                arrival.insert(next_state, next_tick);
+                parent.insert(next_state, state);
+                heap.push(Reverse((next_tick + heuristic(next), next_tick, next.x, next.y)));
+            }
+        }
+    }
+    
+    finish(Vec::new(), nodes_explored, SearchStatus::NoPath)
+}
+
+pub fn timed_bfs_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
+    timed_search(maze, budget, "Timed BFS", false)
+}
+
+pub fn timed_astar_solve(maze: &Maze, budget: &SearchBudget) -> Solution {
+    timed_search(maze, budget, "Timed A*", true)
+}
+
+fn count_waits(path: &[Point]) -> usize {
+    path.windows(2).filter(|w| w[0] == w[1]).count()
+}
+
+fn render_tick(maze: &Maze, tick: usize, schedule: &[Point]) {
+    let position = schedule[tick];
+    let mut marks = HashMap::new();
+    for (y, row) in maze.grid.iter().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            if matches!(cell, Cell::Periodic { .. }) && !open_at(*cell, tick) {
+                marks.insert(Point { x, y }, "▓▓".to_string());
+            }
+        }
+    }
+    
+    let action = match tick {
+        0 => "start".to_string(),
+        _ if schedule[tick - 1] == position => "wait".to_string(),
+        _ => format!("move to ({}, {})", position.x, position.y),
+    };
+    println!("\n--- Tick {} ({}) ---", tick, action);
+    let tokens = HashMap::from([(position, "@@".to_string())]);
+    maze.display_with_tokens(&schedule[..tick], &marks, &tokens);
+}
+
+pub fn run_timed() {
+    let (width, height) = crate::get_size_from_user();
+    let gate_count = crate::prompt_number("Number of timed gates on the route (default: 4)", 4, |_: &usize| true);
+    let patrol_count = crate::prompt_number("Number of patrolling hazards crossing the route (default: 2)", 2, |_: &usize| true);
+    let show_visualizations = crate::get_display_choice();
+    let budget = crate::get_budget_from_user();
+    crate::install_cancel_handler(&budget);
+    
+    println!("\nGenerating random maze ({width}x{height})...");
+    let mut maze = Maze::new(width, height);
+    let (gates, patrols) = place_gates_and_patrols(&mut maze, gate_count, patrol_count, &mut rand::thread_rng());
+    println!("Placed {} gate(s) and {} patrol(s); the schedule repeats every {} ticks.", gates, patrols, schedule_period(&maze));
+    
+    println!("\n=== Maze (░░ cells open and close over time) ===");
+    maze.display_with_path(&[]);
+    
+    let static_route = bfs_solve(&maze, &budget);
+    let solutions = [timed_bfs_solve(&maze, &budget), timed_astar_solve(&maze, &budget)];
+    
+    println!("\n=== SUMMARY (static shortest route: {} moves) ===", static_route.path.len().saturating_sub(1));
+    println!("{:<16} | {:<12} | {:<6} | {:<15} | {:<12} | {:<10}",
+             "Algorithm", "Arrival Tick", "Waits", "States Explored", "Time (μs)", "Status");
+    println!("{}", "-".repeat(88));
+    for solution in &solutions {
+        println!("{:<16} | {:<12} | {:<6} | {:<15} | {:<12} | {:<10}",
+                 solution.algorithm,
+                 solution.path.len().saturating_sub(1),
+                 count_waits(&solution.path),
+                 solution.nodes_explored,
+                 solution.time_taken,
+                 solution.status.label());
+    }
+    println!("{}", "-".repeat(88));
+    
+    let best = &solutions[1];
+    if best.status != SearchStatus::Solved {
+        println!("No schedule reaches the goal.");
+        return;
+    }
+    
+    if show_visualizations {
+        println!("\n=== {} Schedule (@@ agent, ▓▓ closed, ░░ open) ===", best.algorithm);
+        for tick in 0..best.path.len() {
+            render_tick(&maze, tick, &best.path);
+        }
+    }
+}